@@ -2,16 +2,20 @@ package main
 
 import (
 	"context"
+	"encoding/json"
 	"fmt"
 	"io"
 	"log/slog"
 	"os"
+	"os/signal"
 	"runtime/pprof"
 	"strings"
+	"time"
 
 	"github.com/charmbracelet/fang"
 	"github.com/creachadair/jrpc2"
 	"github.com/creachadair/jrpc2/channel"
+	"github.com/pmezard/go-difflib/difflib"
 	"github.com/spf13/cobra"
 	"github.com/vito/dang/v2/pkg/dang"
 	"github.com/vito/dang/v2/pkg/ioctx"
@@ -21,6 +25,7 @@ import (
 // Config holds the application configuration
 type Config struct {
 	Debug      bool
+	Verbose    bool
 	DebugAddr  string
 	ClearCache bool
 	File       string
@@ -46,10 +51,14 @@ It provides type-safe, composable abstractions for container operations.`,
 
   # Start interactive REPL
   dang
+  dang repl
 
   # Run with debug logging enabled
   dang --debug script.dang
-  dang -d ./my-module`,
+  dang -d ./my-module
+
+  # Print each function call and Dagger query as it's evaluated
+  dang --verbose ./my-module`,
 		Args: cobra.MaximumNArgs(1),
 		RunE: func(cmd *cobra.Command, args []string) error {
 			// Start CPU profile if requested.
@@ -96,7 +105,9 @@ It provides type-safe, composable abstractions for container operations.`,
 	}
 
 	// Add flags
-	rootCmd.Flags().BoolVarP(&cfg.Debug, "debug", "d", false, "Enable debug logging")
+	// --debug is persistent so subcommands like repl share the root config.
+	rootCmd.PersistentFlags().BoolVarP(&cfg.Debug, "debug", "d", false, "Enable debug logging")
+	rootCmd.Flags().BoolVar(&cfg.Verbose, "verbose", false, "Print progress (function calls and GraphQL queries) to stderr")
 	rootCmd.Flags().StringVar(&cfg.DebugAddr, "debug-addr", "", "Serve debug/pprof handlers on this address (e.g. localhost:6060)")
 	rootCmd.Flags().BoolVar(&cfg.ClearCache, "clear-cache", false, "Clear GraphQL schema cache and exit")
 	rootCmd.Flags().BoolVar(&cfg.LSP, "lsp", false, "Run in Language Server Protocol mode")
@@ -105,6 +116,10 @@ It provides type-safe, composable abstractions for container operations.`,
 
 	// Add subcommands
 	rootCmd.AddCommand(fmtCmd())
+	rootCmd.AddCommand(checkCmd())
+	rootCmd.AddCommand(docCmd())
+	rootCmd.AddCommand(testCmd())
+	rootCmd.AddCommand(replCmd(&cfg))
 
 	// Use fang for styled execution with enhanced features
 	ctx := context.Background()
@@ -135,6 +150,10 @@ func run(ctx context.Context, cfg Config) error {
 	logger := slog.New(handler)
 	slog.SetDefault(logger)
 
+	if cfg.Verbose {
+		ctx = dang.WithProgress(ctx, dang.ProgressPrinter(ioctx.StderrFromContext(ctx)))
+	}
+
 	// Check if the path is a directory or file
 	fileInfo, err := os.Stat(cfg.File)
 	if err != nil {
@@ -245,10 +264,278 @@ func (stdrwc) Close() error {
 	return os.Stdout.Close()
 }
 
+func replCmd(cfg *Config) *cobra.Command {
+	cmd := &cobra.Command{
+		Use:   "repl [flags]",
+		Short: "Start an interactive REPL",
+		Long: `Start an interactive REPL, the same as running dang with no path.
+
+Each input is type-checked and evaluated in a session that persists between
+inputs, so let bindings carry over to later lines. Results are printed with
+their type. Input with an unclosed brace, bracket, string, or template, or
+ending in a "." mid-chain, continues onto the next line.`,
+		Args: cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runREPL(cmd.Context(), *cfg)
+		},
+	}
+
+	return cmd
+}
+
+func checkCmd() *cobra.Command {
+	var format string
+	var watch bool
+
+	cmd := &cobra.Command{
+		Use:   "check [flags] [file|directory]",
+		Short: "Type-check Dang code without running it",
+		Long: `Type-check a Dang file or module without evaluating it.
+
+Errors are printed with their source, the same as when running. With
+--format json, they are instead printed to stdout as a JSON array, one entry
+per error with its file, 1-based start and end line and column (the end
+column is exclusive), severity, message, and any suggestion. The array is
+empty when there are no errors. check exits non-zero if there were any.
+
+With --watch, check keeps running and re-checks whenever a .dang file (or
+dang.toml) changes, reprinting the errors each time, or "no errors" once they
+are all fixed. With --format json, each check prints one JSON array per line.
+
+The path defaults to the current directory.`,
+		Example: `  # Check the module in the current directory
+  dang check
+
+  # Check a single file
+  dang check script.dang
+
+  # Report errors as JSON for CI
+  dang check --format json ./my-module
+
+  # Re-check on every save
+  dang check --watch`,
+		Args: cobra.MaximumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			path := "."
+			if len(args) == 1 {
+				path = args[0]
+			}
+			if watch {
+				return runCheckWatch(cmd.Context(), path, format)
+			}
+			return runCheck(cmd.Context(), path, format)
+		},
+	}
+
+	cmd.Flags().StringVar(&format, "format", "text", "Output format: text or json")
+	cmd.Flags().BoolVarP(&watch, "watch", "w", false, "Re-check whenever a file changes")
+
+	return cmd
+}
+
+func runCheck(ctx context.Context, path, format string) error {
+	switch format {
+	case "text":
+		return dang.Check(ctx, path)
+	case "json":
+		diags := dang.Diagnostics(dang.Check(ctx, path))
+		if diags == nil {
+			diags = []dang.Diagnostic{}
+		}
+		out, err := json.MarshalIndent(diags, "", "  ")
+		if err != nil {
+			return err
+		}
+		fmt.Println(string(out))
+		if len(diags) > 0 {
+			return fmt.Errorf("%d error(s)", len(diags))
+		}
+		return nil
+	default:
+		return fmt.Errorf("unknown format %q; want text or json", format)
+	}
+}
+
+// runCheckWatch re-runs check on every change until interrupted. In text
+// mode the screen is cleared before each report on a terminal, so only the
+// current errors are shown.
+func runCheckWatch(ctx context.Context, path, format string) error {
+	if format != "text" && format != "json" {
+		return fmt.Errorf("unknown format %q; want text or json", format)
+	}
+	ctx, stop := signal.NotifyContext(ctx, os.Interrupt)
+	defer stop()
+
+	clearScreen := isTerminal(os.Stdout)
+	clean := false
+	return dang.Watch(ctx, path, func(err error) {
+		if format == "json" {
+			diags := dang.Diagnostics(err)
+			if diags == nil {
+				diags = []dang.Diagnostic{}
+			}
+			out, marshalErr := json.Marshal(diags)
+			if marshalErr != nil {
+				_, _ = fmt.Fprintln(os.Stderr, marshalErr)
+				return
+			}
+			fmt.Println(string(out))
+			return
+		}
+		if err == nil && clean {
+			// Still clean; nothing new to say.
+			return
+		}
+		if clearScreen {
+			fmt.Print("\x1b[H\x1b[2J")
+		}
+		clean = err == nil
+		if clean {
+			fmt.Println("no errors")
+			return
+		}
+		_, _ = fmt.Fprintln(os.Stderr, err.Error())
+		_, _ = fmt.Fprintf(os.Stderr, "%d error(s); watching for changes...\n", len(dang.Diagnostics(err)))
+	})
+}
+
+// isTerminal reports whether f is attached to a terminal.
+func isTerminal(f *os.File) bool {
+	info, err := f.Stat()
+	return err == nil && info.Mode()&os.ModeCharDevice != 0
+}
+
+func docCmd() *cobra.Command {
+	var asJSON bool
+
+	cmd := &cobra.Command{
+		Use:   "doc [flags] [file|directory]",
+		Short: "Print documentation for a module's public API",
+		Long: `Type-check a Dang file or module and print its public types, fields, and
+methods as Markdown, in declaration order.
+
+Each type's fields and methods are grouped under it, with their signatures
+(including argument types and defaults) and doc strings. With --json, the same
+information is printed as a JSON object instead.
+
+The path defaults to the current directory.`,
+		Example: `  # Document the module in the current directory
+  dang doc
+
+  # Emit JSON for another tool to render
+  dang doc --json ./my-module`,
+		Args: cobra.MaximumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			path := "."
+			if len(args) == 1 {
+				path = args[0]
+			}
+			return runDoc(cmd.Context(), path, asJSON)
+		},
+	}
+
+	cmd.Flags().BoolVar(&asJSON, "json", false, "Print documentation as JSON")
+
+	return cmd
+}
+
+func runDoc(ctx context.Context, path string, asJSON bool) error {
+	doc, err := dang.Doc(ctx, path)
+	if err != nil {
+		return err
+	}
+	if !asJSON {
+		fmt.Print(doc.Markdown())
+		return nil
+	}
+	out, err := json.MarshalIndent(doc, "", "  ")
+	if err != nil {
+		return err
+	}
+	fmt.Println(string(out))
+	return nil
+}
+
+func testCmd() *cobra.Command {
+	var filter string
+
+	cmd := &cobra.Command{
+		Use:   "test [flags] [directory]",
+		Short: "Run the test functions in a Dang module",
+		Long: `Run the test functions in a Dang module.
+
+A test function is any public function whose name starts with "test" and
+that takes no required arguments. Each one is called after the module is
+evaluated; it fails if it raises an error (e.g. a failed assert) or returns
+false. test exits non-zero if any test failed.
+
+The directory defaults to the current directory.`,
+		Example: `  # Run every test in the current module
+  dang test
+
+  # Run the tests in another module
+  dang test ./my-module
+
+  # Run only tests whose name contains "parse"
+  dang test --filter parse`,
+		Args: cobra.MaximumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			dir := "."
+			if len(args) == 1 {
+				dir = args[0]
+			}
+			return runTests(cmd.Context(), dir, filter)
+		},
+	}
+
+	cmd.Flags().StringVar(&filter, "filter", "", "Only run tests whose name contains this substring")
+
+	return cmd
+}
+
+func runTests(ctx context.Context, dir, filter string) error {
+	info, err := os.Stat(dir)
+	if err != nil {
+		return fmt.Errorf("failed to access path %s: %w", dir, err)
+	}
+	if !info.IsDir() {
+		return fmt.Errorf("%s is not a directory; dang test runs a module's tests", dir)
+	}
+
+	results, err := dang.RunTests(ctx, dir, filter)
+	if err != nil {
+		return err
+	}
+
+	if len(results) == 0 {
+		fmt.Println("no tests found")
+		return nil
+	}
+
+	var failed int
+	for _, result := range results {
+		duration := result.Duration.Round(time.Microsecond)
+		if result.Passed() {
+			fmt.Printf("PASS %s (%s)\n", result.Name, duration)
+			continue
+		}
+		failed++
+		fmt.Printf("FAIL %s (%s)\n", result.Name, duration)
+		fmt.Println(result.Err.Error())
+	}
+
+	if failed > 0 {
+		return fmt.Errorf("%d of %d test(s) failed", failed, len(results))
+	}
+	fmt.Printf("%d test(s) passed\n", len(results))
+	return nil
+}
+
 func fmtCmd() *cobra.Command {
 	var (
 		write bool
 		list  bool
+		check bool
 	)
 
 	cmd := &cobra.Command{
@@ -258,7 +545,9 @@ func fmtCmd() *cobra.Command {
 
 By default, fmt prints the formatted source to stdout.
 Use -w to write the result back to the source file.
-Use -l to list files that would be changed.`,
+Use -l to list files that would be changed.
+Use --check to print a diff for each unformatted file and exit non-zero,
+e.g. in CI.`,
 		Example: `  # Format a file and print to stdout
   dang fmt script.dang
 
@@ -269,20 +558,27 @@ Use -l to list files that would be changed.`,
   dang fmt -w ./my-module
 
   # List files that need formatting
-  dang fmt -l ./my-module`,
+  dang fmt -l ./my-module
+
+  # Fail if any file needs formatting, showing what would change
+  dang fmt --check ./my-module`,
 		Args: cobra.MinimumNArgs(1),
 		RunE: func(cmd *cobra.Command, args []string) error {
-			return runFmt(args, write, list)
+			if check && write {
+				return fmt.Errorf("--check and -w are mutually exclusive")
+			}
+			return runFmt(args, write, list, check)
 		},
 	}
 
 	cmd.Flags().BoolVarP(&write, "write", "w", false, "Write result to source file instead of stdout")
 	cmd.Flags().BoolVarP(&list, "list", "l", false, "List files that would be formatted")
+	cmd.Flags().BoolVar(&check, "check", false, "Print a diff for unformatted files and exit non-zero if there are any")
 
 	return cmd
 }
 
-func runFmt(paths []string, write, list bool) error {
+func runFmt(paths []string, write, list, check bool) error {
 	var files []string
 
 	for _, path := range paths {
@@ -307,6 +603,23 @@ func runFmt(paths []string, write, list bool) error {
 		}
 	}
 
+	if check {
+		var unformatted int
+		for _, file := range files {
+			changed, err := checkFile(file, list)
+			if err != nil {
+				return fmt.Errorf("formatting %s: %w", file, err)
+			}
+			if changed {
+				unformatted++
+			}
+		}
+		if unformatted > 0 {
+			return fmt.Errorf("%d of %d file(s) not formatted", unformatted, len(files))
+		}
+		return nil
+	}
+
 	for _, file := range files {
 		if err := formatFile(file, write, list); err != nil {
 			return fmt.Errorf("formatting %s: %w", file, err)
@@ -316,6 +629,42 @@ func runFmt(paths []string, write, list bool) error {
 	return nil
 }
 
+// checkFile reports whether path differs from its formatted form, printing
+// a unified diff of the change (or, with list, just the path).
+func checkFile(path string, list bool) (bool, error) {
+	source, err := os.ReadFile(path)
+	if err != nil {
+		return false, err
+	}
+
+	formatted, err := dang.FormatFile(source)
+	if err != nil {
+		return false, err
+	}
+
+	if string(source) == formatted {
+		return false, nil
+	}
+
+	if list {
+		fmt.Println(path)
+		return true, nil
+	}
+
+	diff, err := difflib.GetUnifiedDiffString(difflib.UnifiedDiff{
+		A:        difflib.SplitLines(string(source)),
+		B:        difflib.SplitLines(formatted),
+		FromFile: path,
+		ToFile:   path + " (formatted)",
+		Context:  3,
+	})
+	if err != nil {
+		return false, err
+	}
+	fmt.Print(diff)
+	return true, nil
+}
+
 func formatFile(path string, write, list bool) error {
 	source, err := os.ReadFile(path)
 	if err != nil {
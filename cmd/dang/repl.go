@@ -1,9 +1,13 @@
 package main
 
 import (
+	"fmt"
 	"strings"
 
 	"charm.land/lipgloss/v2"
+
+	"github.com/vito/dang/v2/pkg/dang"
+	"github.com/vito/dang/v2/pkg/hm"
 )
 
 // replEntry groups an input line with its associated output.
@@ -38,3 +42,135 @@ var (
 	welcomeStyle = lipgloss.NewStyle().Foreground(lipgloss.Color("5"))            // magenta
 	dimStyle     = lipgloss.NewStyle().Foreground(lipgloss.Color("8"))            // bright black
 )
+
+// formatResult renders an evaluated value and its type for the REPL. The
+// type is the one inferred for the input when there is one, falling back to
+// the value's runtime type.
+//
+// A GraphQL object like Container! is a lazy query that hasn't run yet, so
+// it's described rather than printed; selecting a scalar field from it is what
+// sends the query.
+func formatResult(val dang.Value, typ hm.Type) string {
+	if typ == nil {
+		typ = val.Type()
+	}
+	var shown string
+	if gql, ok := val.(dang.GraphQLValue); ok {
+		shown = dimStyle.Render(fmt.Sprintf("<lazy %s from .%s>", gql.TypeName, gql.Name))
+	} else {
+		shown = highlightCode(dang.Repr(val))
+	}
+	if typ == nil {
+		return shown
+	}
+	return shown + dimStyle.Render(" : "+typ.String())
+}
+
+// inputIncomplete reports whether src stops partway through an expression —
+// an unclosed bracket, string, or template, or a chain ending in a trailing
+// "." — so Enter should start a new line instead of evaluating it.
+func inputIncomplete(src string) bool {
+	// Each open template, innermost last, like the lexer tracks them: the
+	// length of its backtick fence, and the brace depth of its open
+	// interpolation, or -1 while in its literal text.
+	type template struct {
+		fence int
+		depth int
+	}
+	var templates []*template
+
+	depth := 0
+	var last byte // last byte outside of whitespace and comments
+	for i := 0; i < len(src); i++ {
+		var tmpl *template
+		if len(templates) > 0 {
+			tmpl = templates[len(templates)-1]
+		}
+		if tmpl != nil && tmpl.depth < 0 {
+			// Template text: only \${ is an escape, and only a run of
+			// backticks as long as the fence closes it.
+			switch {
+			case strings.HasPrefix(src[i:], "\\${"):
+				i += 2
+			case strings.HasPrefix(src[i:], "${"):
+				tmpl.depth = 0
+				i++
+			case src[i] == '`' && tmpl.fence == 1:
+				templates = templates[:len(templates)-1]
+				last = '`'
+			case src[i] == '`':
+				run := backtickRun(src, i)
+				i += run - 1
+				if run == tmpl.fence {
+					templates = templates[:len(templates)-1]
+					last = '`'
+				}
+			}
+			continue
+		}
+
+		c := src[i]
+		switch c {
+		case ' ', '\t', '\r', '\n':
+			continue
+		case '#':
+			for i < len(src) && src[i] != '\n' {
+				i++
+			}
+			continue
+		case '"':
+			if strings.HasPrefix(src[i:], `"""`) {
+				end := strings.Index(src[i+3:], `"""`)
+				if end == -1 {
+					return true
+				}
+				i += 3 + end + 2
+				break
+			}
+			i++
+			for i < len(src) && src[i] != '"' {
+				if src[i] == '\\' {
+					i++
+				}
+				i++
+			}
+			if i >= len(src) {
+				return true
+			}
+		case '`':
+			run := backtickRun(src, i)
+			if run != 2 {
+				// A pair of backticks is an empty template; anything else
+				// opens one.
+				templates = append(templates, &template{fence: run, depth: -1})
+			}
+			i += run - 1
+		case '{', '(', '[':
+			if c == '{' && tmpl != nil {
+				tmpl.depth++
+			}
+			depth++
+		case '}', ')', ']':
+			if c == '}' && tmpl != nil {
+				if tmpl.depth == 0 {
+					// The interpolation closes; back to the template text.
+					tmpl.depth = -1
+					continue
+				}
+				tmpl.depth--
+			}
+			depth--
+		}
+		last = c
+	}
+	return len(templates) > 0 || depth > 0 || last == '.'
+}
+
+// backtickRun returns the number of consecutive backticks at src[i:].
+func backtickRun(src string, i int) int {
+	n := 0
+	for i+n < len(src) && src[i+n] == '`' {
+		n++
+	}
+	return n
+}
@@ -0,0 +1,40 @@
+package main
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestInputIncomplete(t *testing.T) {
+	for _, tc := range []struct {
+		input      string
+		incomplete bool
+	}{
+		{`1 + 2`, false},
+		{`let x = 1`, false},
+		{`[1, 2].map { n =>`, true},
+		{"[1, 2].map { n =>\n  n * 2\n}", false},
+		{`foo(1,`, true},
+		{`[1, 2`, true},
+		{`container.from("alpine").`, true},
+		{`container.from("alpine")  # then .withExec`, false},
+		{`"unterminated`, true},
+		{`"escaped \" quote"`, false},
+		{`"a { inside a string"`, false},
+		{`"""` + "\nstill going", true},
+		{`"""` + "\ndone\n" + `"""`, false},
+		{"`tmpl ${x}", true},
+		{"`tmpl ${x}`", false},
+		{`x # { not a brace`, false},
+		{"```\nhas a ` inside\n```", false},
+		{"```\nhas a ` inside", true},
+		{"````\nholds ``` too\n````", false},
+		{"`a ${[`b`, `c`].length} d`", false},
+		{"`a ${`inner`", true},
+		{"`C:\\`", false},
+		{"`not \\${open}`", false},
+	} {
+		assert.Equal(t, tc.incomplete, inputIncomplete(tc.input), "input: %q", tc.input)
+	}
+}
@@ -235,6 +235,14 @@ func newReplComponent(ctx context.Context, importConfigs []dang.ImportConfig, de
 		if key.Code != 'c' || key.Mod != uv.ModCtrl {
 			r.ctrlCPending = false
 		}
+		// Enter on an unfinished expression (an open brace, a trailing
+		// "." in a chain) keeps reading on a new line, like Alt+Enter.
+		if key.Code == uv.KeyEnter && key.Mod == 0 {
+			if value := ti.Value(); !strings.HasPrefix(value, ":") && inputIncomplete(value) {
+				ti.SetValue(value + "\n")
+				return true
+			}
+		}
 		return false // let TextInput handle the key normally
 	}
 
@@ -481,8 +489,9 @@ func (r *replComponent) startEval(ectx tuist.Context, expr string) {
 			}
 
 			// Echo the result as a highlighted Dang literal (quoted strings,
-			// same grammar as the input), behind a green "=> " marker.
-			results = append(results, resultStyle.Render("=> ")+highlightCode(dang.Repr(val)))
+			// same grammar as the input), behind a green "=> " marker,
+			// followed by its type.
+			results = append(results, resultStyle.Render("=> ")+formatResult(val, node.GetInferredType()))
 			if debug {
 				results = append(results, dimStyle.Render(fmt.Sprintf("%# v", pretty.Formatter(val))))
 			}
@@ -677,7 +677,7 @@ func (r *Reassignment) performAddition(left, right Value, varName string) (Value
 	case IntValue:
 		switch r := right.(type) {
 		case IntValue:
-			return IntValue{Val: l.Val + r.Val}, nil
+			return addInts(l.Val, r.Val)
 		case FloatValue:
 			return FloatValue{Val: float64(l.Val) + r.Val}, nil
 		}
@@ -378,10 +378,29 @@ func newGraphQLError(list gqlerror.List) *Object {
 		}
 	}
 	mv.Bind("extensions", StringValue{Val: extensions}, PublicVisibility)
+	mv.Bind("exitCode", graphqlErrorExitCode(first.Extensions), PublicVisibility)
 
 	return mv
 }
 
+// graphqlErrorExitCode returns the exitCode extension Dagger attaches to a
+// failed exec, or null when there isn't one.
+func graphqlErrorExitCode(extensions map[string]any) Value {
+	switch code := extensions["exitCode"].(type) {
+	case int:
+		return IntValue{Val: code}
+	case int64:
+		return IntValue{Val: int(code)}
+	case float64:
+		return IntValue{Val: int(code)}
+	case json.Number:
+		if n, err := code.Int64(); err == nil {
+			return IntValue{Val: int(n)}
+		}
+	}
+	return NullValue{}
+}
+
 func (t *RescueExpr) Walk(fn func(Node) bool) {
 	if !fn(t) {
 		return
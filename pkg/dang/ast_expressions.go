@@ -152,9 +152,10 @@ func (c *FunCall) inferFunctionType(ctx context.Context, env hm.Env, fresh hm.Fr
 	if !ok {
 		return nil, fmt.Errorf("FunCall.Infer: expected record type for arguments, got %T", ft.Arg())
 	}
+	schemaField := c.callsSchemaField(ft)
 	for i, arg := range c.Args {
 		k := c.getArgumentKey(arg, argMapping, i)
-		coerced, subs, err := c.checkArgumentTypeWithSubs(ctx, env, fresh, arg.Value, argRecord, k)
+		coerced, subs, err := c.checkArgumentTypeWithSubs(ctx, env, fresh, arg.Value, argRecord, k, schemaField)
 		if err != nil {
 			return nil, fmt.Errorf("argument %q: %w", k, err)
 		}
@@ -178,6 +179,16 @@ func (c *FunCall) inferFunctionType(ctx context.Context, env hm.Env, fresh hm.Fr
 		ft = ft.Apply(argSubs).(*hm.FunctionType)
 	}
 
+	// A Numeric builtin's type variables stand for "Int or Float", which
+	// plain unification can't express, so check them here.
+	var numericRet hm.Type
+	if def, ok := c.numericBuiltin(); ok {
+		numericRet, err = c.checkNumericArguments(def, ft, argMapping)
+		if err != nil {
+			return nil, err
+		}
+	}
+
 	// Handle block arg if present (needs special bidirectional inference).
 	// The block body sees this call as its break target.
 	var blockArgSubs hm.Subs
@@ -205,6 +216,9 @@ func (c *FunCall) inferFunctionType(ctx context.Context, env hm.Env, fresh hm.Fr
 
 	// Apply block arg substitutions to the return type
 	retType := ft.Ret(false)
+	if numericRet != nil {
+		retType = numericRet
+	}
 	if blockArgSubs != nil {
 		retType = retType.Apply(blockArgSubs).(hm.Type)
 	}
@@ -275,7 +289,9 @@ func (c *FunCall) getArgumentKey(arg Keyed[Node], argMapping map[int]string, ind
 // parameter type and returns the (possibly Coerce-wrapped) argument node along
 // with any substitutions produced by unification. The substitutions are used to
 // resolve type variables from argument types before inferring block args.
-func (c *FunCall) checkArgumentTypeWithSubs(ctx context.Context, env hm.Env, fresh hm.Fresher, value Node, recordType *RecordType, key string) (Node, hm.Subs, error) {
+// schemaField reports whether the callee is a schema field, whose constant
+// arguments get checkConstantArgument's name-based checks.
+func (c *FunCall) checkArgumentTypeWithSubs(ctx context.Context, env hm.Env, fresh hm.Fresher, value Node, recordType *RecordType, key string, schemaField bool) (Node, hm.Subs, error) {
 	scheme, has := recordType.SchemeOf(key)
 	if !has {
 		return value, nil, fmt.Errorf("FunCall.Infer: %q not found in %s", key, recordType)
@@ -296,9 +312,70 @@ func (c *FunCall) checkArgumentTypeWithSubs(ctx context.Context, env hm.Env, fre
 	if err != nil {
 		return value, nil, NewInferError(err, value)
 	}
+	if err := checkLiteralScalar(value, dt, key); err != nil {
+		return value, nil, NewInferError(err, value)
+	}
+	if err := checkConstantArgument(value, dt, key, schemaField); err != nil {
+		return value, nil, NewInferError(err, value)
+	}
 	return wrapCoerce(value, dt, key), subs, nil
 }
 
+// numericBuiltin returns the builtin this call invokes, if it's a static
+// method marked Numeric. Builtin statics are fields of their module's value
+// (see registerBuiltinTypes), so they're found through the receiver's type
+// rather than staticOwner, which only covers statics declared in Dang.
+func (c *FunCall) numericBuiltin() (BuiltinDef, bool) {
+	sel, ok := c.Fun.(*Select)
+	if !ok || sel.Receiver == nil {
+		return BuiltinDef{}, false
+	}
+	mod := moduleOf(inferredTypeOf(sel.Receiver))
+	if mod == nil {
+		return BuiltinDef{}, false
+	}
+	def, ok := LookupStaticMethod(mod, sel.Field.Name)
+	return def, ok && def.Numeric
+}
+
+// checkNumericArguments rejects any argument to a Numeric builtin that isn't
+// an Int or Float. When the builtin returns one of its type variables, it
+// returns the result type the way arithmetic would: Float if any argument is
+// a Float, and nullable if any argument is. It returns nil when the return
+// type needs no adjusting, including when an argument is still an open type
+// variable.
+func (c *FunCall) checkNumericArguments(def BuiltinDef, ft *hm.FunctionType, argMapping map[int]string) (hm.Type, error) {
+	argRecord := ft.Arg().(*RecordType)
+	result := IntType
+	nonNull := true
+	open := false
+	for i, arg := range c.Args {
+		k := c.getArgumentKey(arg, argMapping, i)
+		scheme, has := argRecord.SchemeOf(k)
+		if !has {
+			continue
+		}
+		t, _ := scheme.Type()
+		if hasFreeVar(t) {
+			open = true
+			continue
+		}
+		base, nn := stripNonNull(t)
+		if !isNumeric(base) {
+			return nil, NewInferError(fmt.Errorf("%s.%s: %s must be an Int or Float, got %s",
+				def.HostModule.Named, def.Name, k, t), arg.Value)
+		}
+		if base == FloatType {
+			result = FloatType
+		}
+		nonNull = nonNull && nn
+	}
+	if _, ok := def.ReturnType.(hm.TypeVariable); !ok || open {
+		return nil, nil
+	}
+	return withNonNull(result, nonNull), nil
+}
+
 var _ hm.Apply = (*FunCall)(nil)
 
 func (c *FunCall) Fn() hm.Expression { return c.Fun }
@@ -347,6 +424,8 @@ func (c *FunCall) Eval(ctx context.Context, scope ValueScope) (Value, error) {
 		}
 
 		// Dispatch to appropriate function call handler
+		ctx = withProgressLocation(ctx, c.Loc)
+		reportCall(ctx, progressName(c.Fun), funVal, c.Loc)
 		val, err := c.callFunction(ctx, scope, funVal, argValues)
 		if err != nil && blockCallFrame != nil {
 			var breakEx *BreakException
@@ -384,12 +463,13 @@ func (c *FunCall) evaluateArguments(ctx context.Context, scope ValueScope, funVa
 	// Process all arguments. Each arg.Value is wrapped in a Coerce by
 	// FunCall.checkArgumentTypeWithSubs when types are known, so
 	// materialization happens during EvalNode.
+	vals, err := c.evaluateArgumentValues(ctx, scope)
+	if err != nil {
+		return nil, err
+	}
 	positionalIndex := 0
-	for _, arg := range c.Args {
-		val, err := EvalNode(ctx, scope, arg.Value)
-		if err != nil {
-			return nil, err
-		}
+	for i, arg := range c.Args {
+		val := vals[i]
 
 		if arg.Positional {
 			err := c.handlePositionalArgument(arg, val, argValues, positionallySet, paramNames, &positionalIndex)
@@ -410,6 +490,145 @@ func (c *FunCall) evaluateArguments(ctx context.Context, scope ValueScope, funVa
 	return argValues, nil
 }
 
+// evaluateArgumentValues evaluates the argument values in source order. A
+// call's arguments don't depend on each other, so when more than one of them
+// resolves a GraphQL selection (e.g. a Dagger operation) and none runs Dang
+// code, they're evaluated concurrently, like the fields of a `{{ }}` record.
+// Each forks the scope so incidental local writes stay private, and
+// evalParallel reports the lowest-index failure, so the error and its span
+// match what sequential evaluation would report.
+func (c *FunCall) evaluateArgumentValues(ctx context.Context, scope ValueScope) ([]Value, error) {
+	if argsRunConcurrently(c.Args) {
+		return evalParallel(ctx, len(c.Args), func(ctx context.Context, i int) (Value, error) {
+			return EvalNode(ctx, scope.Derive(true), c.Args[i].Value)
+		})
+	}
+	vals := make([]Value, len(c.Args))
+	for i, arg := range c.Args {
+		val, err := EvalNode(ctx, scope, arg.Value)
+		if err != nil {
+			return nil, err
+		}
+		vals[i] = val
+	}
+	return vals, nil
+}
+
+// argsRunConcurrently reports whether a call's arguments are worth evaluating
+// concurrently: at least two of them must do more than read a name or a
+// literal, and every one must be a pure selection (see selectionOnly). A Dang
+// function's body may print, assert, or export, none of which shows at the
+// call site, so any argument that calls one keeps the source order.
+func argsRunConcurrently(args []Keyed[Node]) bool {
+	working := 0
+	for _, arg := range args {
+		if !selectionOnly(arg.Value) {
+			return false
+		}
+		if !trivialArg(arg.Value) {
+			working++
+		}
+	}
+	return working > 1
+}
+
+// selectionOnly reports whether evaluating node runs no Dang code: it's
+// built only from literals, plain reads, and schema field selections whose
+// arguments are the same. Mutations have ordering-sensitive effects of their
+// own, so they don't count. It relies on the types recorded during inference,
+// as the rescue laziness analysis does, and anything else counts as running
+// code.
+func selectionOnly(node Node) bool {
+	switch n := node.(type) {
+	case *Coerce:
+		return selectionOnly(n.Expr)
+	case *Grouped:
+		return selectionOnly(n.Expr)
+	case *Null, *Boolean, *Int, *Float, *String, *SelfKeyword:
+		return true
+	case *Template:
+		for _, part := range n.Parts {
+			if part.Expr != nil && !selectionOnly(part.Expr) {
+				return false
+			}
+		}
+		return true
+	case *List:
+		for _, el := range n.Elements {
+			if !selectionOnly(el) {
+				return false
+			}
+		}
+		return true
+	case *Symbol:
+		return classifyGQLSymbol(n) != notGQLField || !n.callsFunction
+	case *Select:
+		if n.Receiver == nil || n.staticOwner != nil || !selectionOnly(n.Receiver) {
+			return false
+		}
+		if classifyGQLSelect(n) != notGQLField {
+			return !isMutationSelect(n)
+		}
+		// A stored field read runs nothing; a method or computed field does.
+		if recvMod := moduleOf(inferredTypeOf(n.Receiver)); recvMod != nil {
+			if scheme, found := recvMod.SchemeOf(n.Field.Name); found {
+				if t, mono := scheme.Type(); mono {
+					_, isFn := t.(*hm.FunctionType)
+					return !isFn
+				}
+			}
+		}
+		return false
+	case *FunCall:
+		if n.BlockArg != nil {
+			return false
+		}
+		switch fn := n.Fun.(type) {
+		case *Select:
+			if fn.Receiver == nil || classifyGQLSelect(fn) == notGQLField || isMutationSelect(fn) || !selectionOnly(fn.Receiver) {
+				return false
+			}
+		case *Symbol:
+			if classifyGQLSymbol(fn) == notGQLField {
+				return false
+			}
+		default:
+			return false
+		}
+		for _, arg := range n.Args {
+			if !selectionOnly(arg.Value) {
+				return false
+			}
+		}
+		return true
+	}
+	return false
+}
+
+// isMutationSelect reports whether sel selects a field of the schema's
+// Mutation type.
+func isMutationSelect(sel *Select) bool {
+	mod := schemaObjectModule(inferredTypeOf(sel.Receiver))
+	if mod == nil {
+		return false
+	}
+	mutation := mod.SourceSchema.MutationType
+	return mutation != nil && mutation.Name == mod.Named
+}
+
+// trivialArg reports whether an argument is cheap enough to evaluate inline.
+func trivialArg(node Node) bool {
+	switch n := node.(type) {
+	case *Coerce:
+		return trivialArg(n.Expr)
+	case *Grouped:
+		return trivialArg(n.Expr)
+	case *Symbol, *String, *Int, *Float, *Boolean, *Null, *SelfKeyword:
+		return true
+	}
+	return false
+}
+
 // validateArgumentOrder ensures positional args come before named args
 func (c *FunCall) validateArgumentOrder() error {
 	seenNamed := false
@@ -574,12 +793,25 @@ type Symbol struct {
 	InferredTypeHolder
 	Name     string
 	AutoCall bool
-	Loc      *SourceLocation
+	// callsFunction is set during Infer when the name resolves to a function
+	// that a bare reference auto-calls, i.e. reading it runs code.
+	callsFunction bool
+	Loc           *SourceLocation
 }
 
 var _ Node = (*Symbol)(nil)
 var _ Evaluator = (*Symbol)(nil)
 
+// UndefinedNameError reports a reference to a name that nothing in scope
+// binds.
+type UndefinedNameError struct {
+	Name string
+}
+
+func (e UndefinedNameError) Error() string {
+	return fmt.Sprintf("%q not found", e.Name)
+}
+
 func (s *Symbol) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Type, error) {
 	return WithInferErrorHandling(s, func() (hm.Type, error) {
 		// Check for import conflicts before resolving
@@ -594,10 +826,11 @@ func (s *Symbol) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Ty
 
 		scheme, found := env.SchemeOf(s.Name)
 		if !found {
-			return nil, fmt.Errorf("%q not found", s.Name)
+			return nil, UndefinedNameError{s.Name}
 		}
 		t, _ := scheme.Type()
 		if s.AutoCall {
+			_, s.callsFunction = t.(*hm.FunctionType)
 			var err error
 			t, _, err = autoCallFnType(t)
 			if err != nil {
@@ -870,7 +1103,8 @@ func (d *Select) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Ty
 			// Return a type variable to allow downstream inference to continue
 			tv := fresh.Fresh()
 			d.SetInferredType(tv)
-			return tv, fmt.Errorf("field %q not found in %s", d.Field.Name, rec)
+			return tv, fmt.Errorf("field %q not found in %s%s", d.Field.Name, rec,
+				nameSuggestion(d.Field.Name, bindingNames(rec, PublicVisibility)))
 		}
 		// Module-level visibility: a `let` member is private to the module that
 		// declares its type — reachable anywhere within that module (any file,
@@ -1094,6 +1328,8 @@ func (d *Select) Eval(ctx context.Context, scope ValueScope) (Value, error) {
 
 		// Auto-call zero-arity functions when accessed as symbols
 		if d.AutoCall && isAutoCallableFn(val) {
+			ctx = withProgressLocation(ctx, d.Loc)
+			reportCall(ctx, d.Field.Name, val, d.Loc)
 			return autoCallFn(ctx, scope, val)
 		}
 
@@ -1101,6 +1337,18 @@ func (d *Select) Eval(ctx context.Context, scope ValueScope) (Value, error) {
 	})
 }
 
+// progressName names the function a call evaluates, for progress events.
+func progressName(fun Node) string {
+	switch n := fun.(type) {
+	case *Symbol:
+		return n.Name
+	case *Select:
+		return n.Field.Name
+	default:
+		return "function"
+	}
+}
+
 func (d *Select) Walk(fn func(Node) bool) {
 	if !fn(d) {
 		return
@@ -1137,7 +1385,8 @@ func (i *Index) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Typ
 		}
 
 		// Extract element/value type from the receiver. Maps are indexed by
-		// String!, lists by Int!.
+		// String!, lists by Int!; a mismatched index is reported at the index
+		// expression itself rather than the whole xs[i].
 		var elementType hm.Type
 		var isNullable bool
 
@@ -1155,7 +1404,7 @@ func (i *Index) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Typ
 				return nil, err
 			}
 			if _, err := hm.Assignable(indexType, intType); err != nil {
-				return nil, fmt.Errorf("list index must be Int!, got %s", indexType)
+				return nil, NewInferError(fmt.Errorf("list index must be Int!, got %s", indexType), i.Index)
 			}
 			elementType = recv.Type
 		case MapType:
@@ -1164,7 +1413,7 @@ func (i *Index) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Typ
 				return nil, err
 			}
 			if _, err := hm.Assignable(indexType, strType); err != nil {
-				return nil, fmt.Errorf("map key must be String!, got %s", indexType)
+				return nil, NewInferError(fmt.Errorf("map key must be String!, got %s", indexType), i.Index)
 			}
 			elementType = recv.Type
 		default:
@@ -1924,6 +2173,8 @@ func (o *ObjectSelection) evalGraphQLInlineFragments(gqlVal GraphQLValue, ctx co
 	query = query.SelectMultiple(fragParts...)
 
 	// Execute the query
+	ctx = withProgressLocation(ctx, o.Loc)
+	reportQuery(ctx, fmt.Sprintf("%s.{... on %s}", gqlVal.TypeName, o.inlineFragmentTypeNames()))
 	var result any
 	err := query.Client(gqlVal.Client).Bind(&result).Execute(ctx)
 	if err != nil {
@@ -2036,6 +2287,15 @@ func (o *ObjectSelection) matchLazyInlineFragment(gqlVal GraphQLValue, typeName
 	return NullValue{}, nil
 }
 
+// fieldNames returns a comma-separated list of the selected field names.
+func (o *ObjectSelection) fieldNames() string {
+	names := make([]string, len(o.Fields))
+	for i, field := range o.Fields {
+		names[i] = field.Name
+	}
+	return strings.Join(names, ", ")
+}
+
 // inlineFragmentTypeNames returns a comma-separated list of type names from inline fragments.
 func (o *ObjectSelection) inlineFragmentTypeNames() string {
 	names := make([]string, len(o.InlineFragments))
@@ -2272,6 +2532,8 @@ func (o *ObjectSelection) evalGraphQLSelection(gqlVal GraphQLValue, ctx context.
 	}
 
 	// Execute the single optimized query
+	ctx = withProgressLocation(ctx, o.Loc)
+	reportQuery(ctx, fmt.Sprintf("%s.{%s}", gqlVal.TypeName, o.fieldNames()))
 	var result any
 	err = query.Client(gqlVal.Client).Bind(&result).Execute(ctx)
 	if err != nil {
@@ -404,15 +404,42 @@ func (t *Template) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.
 		if p.Expr == nil {
 			continue
 		}
-		if _, err := p.Expr.Infer(ctx, env, fresh); err != nil {
+		pt, err := p.Expr.Infer(ctx, env, fresh)
+		if err != nil {
 			return nil, err
 		}
+		if isSecretType(pt) {
+			return nil, NewInferError(errSecretAsString, p.Expr)
+		}
+		if !isInterpolatable(pt) {
+			return nil, NewInferError(fmt.Errorf("cannot interpolate %s into a template: only scalars, enums, lists, and maps have a string form", pt), p.Expr)
+		}
 	}
 	tt := t.ConstantType()
 	t.SetInferredType(tt)
 	return tt, nil
 }
 
+// isInterpolatable reports whether a value of type t has a meaningful string
+// form inside a template. Objects, interfaces, and unions (user types, Dagger
+// handles like Container!, records) would otherwise stringify as an internal
+// description, so they are rejected; select a field from them instead.
+func isInterpolatable(t hm.Type) bool {
+	if nn, ok := t.(hm.NonNullType); ok {
+		t = nn.Type
+	}
+	mod, ok := t.(*Type)
+	if !ok {
+		return true
+	}
+	switch mod.Kind {
+	case ObjectKind, InterfaceKind, UnionKind, InputKind:
+		return false
+	default:
+		return true
+	}
+}
+
 func (t *Template) DeclaredSymbols() []string { return nil }
 
 func (t *Template) ReferencedSymbols() []string {
@@ -3,6 +3,7 @@ package dang
 import (
 	"context"
 	"fmt"
+	"math"
 
 	"github.com/vito/dang/v2/pkg/hm"
 )
@@ -96,7 +97,18 @@ func (b *BinaryOperator) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher
 
 		switch b.OpType {
 		case ArithmeticOp:
-			return b.resolveOperands(lt, rt)
+			t, err := b.resolveOperands(lt, rt)
+			if err != nil {
+				return nil, err
+			}
+			// Arithmetic over literals that's bound to overflow is reported
+			// now rather than when it runs. The operands were folded (and
+			// checked) as they were inferred, so the error lands on the
+			// innermost overflowing operator.
+			if _, _, err := b.constantValue(); err != nil {
+				return nil, err
+			}
+			return t, nil
 		case ComparisonOp:
 			// Ordering comparisons (< > <= >=) carry a domain just like
 			// arithmetic and must reject non-orderable operands (e.g.
@@ -106,6 +118,8 @@ func (b *BinaryOperator) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher
 				if _, err := b.resolveOperands(lt, rt); err != nil {
 					return nil, err
 				}
+			} else if err := checkEnumStringComparison(lt, rt); err != nil {
+				return nil, err
 			}
 			return NonNullTypeNode{&NamedTypeNode{nil, "Boolean", b.Loc}}.Infer(ctx, env, fresh)
 		default:
@@ -166,6 +180,10 @@ func (b *BinaryOperator) resolveOperands(lt, rt hm.Type) (hm.Type, error) {
 		return lt.Apply(subs).(hm.Type), nil
 	}
 
+	if b.Operands.has(stringOperand) && (isSecretType(lb) || isSecretType(rb)) {
+		return nil, errSecretAsString
+	}
+
 	if !lt.Eq(rt) {
 		return nil, withUnionProvenance(
 			fmt.Errorf("operator %s is not defined between types %s and %s", b.OpName, lt, rt), lt, rt)
@@ -174,6 +192,29 @@ func (b *BinaryOperator) resolveOperands(lt, rt hm.Type) (hm.Type, error) {
 		fmt.Errorf("operator %s is not defined for type %s", b.OpName, lt), lt)
 }
 
+// checkEnumStringComparison rejects comparing an enum value with a string.
+// Equality is otherwise defined across all types, but an enum value never
+// equals a string at runtime, so `color == "RED"` would silently always be
+// false. Converting explicitly (toString(color) == "RED") or comparing
+// against a value of the enum (color == Color.RED) states what was meant.
+func checkEnumStringComparison(lt, rt hm.Type) error {
+	lb, _ := stripNonNull(lt)
+	rb, _ := stripNonNull(rt)
+	enumSide, strSide := lt, rt
+	if lb == StringType {
+		lb, rb = rb, lb
+		enumSide, strSide = rt, lt
+	}
+	if rb != StringType {
+		return nil
+	}
+	enum, ok := lb.(*Type)
+	if !ok || enum.Kind != EnumKind {
+		return nil
+	}
+	return fmt.Errorf("cannot compare enum %s with %s: compare against a %s value or convert with toString", enumSide, strSide, enum)
+}
+
 // stripNonNull unwraps a NonNullType, reporting whether the wrapper was present.
 func stripNonNull(t hm.Type) (hm.Type, bool) {
 	if nn, ok := t.(hm.NonNullType); ok {
@@ -225,7 +266,7 @@ func additionEval(leftVal, rightVal Value) (Value, error) {
 	case IntValue:
 		switch r := rightVal.(type) {
 		case IntValue:
-			return IntValue{Val: l.Val + r.Val}, nil
+			return addInts(l.Val, r.Val)
 		case FloatValue:
 			return FloatValue{Val: float64(l.Val) + r.Val}, nil
 		}
@@ -264,7 +305,7 @@ func subtractionEval(leftVal, rightVal Value) (Value, error) {
 	case IntValue:
 		switch r := rightVal.(type) {
 		case IntValue:
-			return IntValue{Val: l.Val - r.Val}, nil
+			return subtractInts(l.Val, r.Val)
 		case FloatValue:
 			return FloatValue{Val: float64(l.Val) - r.Val}, nil
 		}
@@ -284,7 +325,7 @@ func multiplicationEval(leftVal, rightVal Value) (Value, error) {
 	case IntValue:
 		switch r := rightVal.(type) {
 		case IntValue:
-			return IntValue{Val: l.Val * r.Val}, nil
+			return multiplyInts(l.Val, r.Val)
 		case FloatValue:
 			return FloatValue{Val: float64(l.Val) * r.Val}, nil
 		}
@@ -307,6 +348,9 @@ func divisionEval(leftVal, rightVal Value) (Value, error) {
 			if r.Val == 0 {
 				return nil, fmt.Errorf("division by zero")
 			}
+			if l.Val == math.MinInt && r.Val == -1 {
+				return nil, intOverflow(l.Val, "/", r.Val)
+			}
 			return IntValue{Val: l.Val / r.Val}, nil
 		case FloatValue:
 			if r.Val == 0 {
@@ -344,6 +388,39 @@ func moduloEval(leftVal, rightVal Value) (Value, error) {
 	return nil, fmt.Errorf("modulo not supported for types %T and %T", leftVal, rightVal)
 }
 
+// Int arithmetic raises on overflow rather than silently wrapping.
+
+func addInts(a, b int) (Value, error) {
+	sum := a + b
+	if (a >= 0) == (b >= 0) && (sum >= 0) != (a >= 0) {
+		return nil, intOverflow(a, "+", b)
+	}
+	return IntValue{Val: sum}, nil
+}
+
+func subtractInts(a, b int) (Value, error) {
+	diff := a - b
+	if (a >= 0) != (b >= 0) && (diff >= 0) != (a >= 0) {
+		return nil, intOverflow(a, "-", b)
+	}
+	return IntValue{Val: diff}, nil
+}
+
+func multiplyInts(a, b int) (Value, error) {
+	if a == 0 || b == 0 {
+		return IntValue{Val: 0}, nil
+	}
+	product := a * b
+	if product/b != a || (a == -1 && b == math.MinInt) || (b == -1 && a == math.MinInt) {
+		return nil, intOverflow(a, "*", b)
+	}
+	return IntValue{Val: product}, nil
+}
+
+func intOverflow(a int, op string, b int) error {
+	return fmt.Errorf("%w: %d %s %d", errIntOverflow, a, op, b)
+}
+
 func inequalityEval(leftVal, rightVal Value) (Value, error) {
 	// Compare the values
 	equal := valuesEqual(leftVal, rightVal)
@@ -477,6 +554,10 @@ func (d *Default) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.T
 			return nil, err
 		}
 
+		if _, ok := lt.(hm.NonNullType); ok {
+			EmitInferWarning(ctx, d, fmt.Sprintf("redundant `??`: the left side is already non-null (%s), so the default is never used", lt))
+		}
+
 		// Return the right type (the fallback value type) with substitutions applied
 		return rt.Apply(subs).(hm.Type), nil
 	})
@@ -535,14 +616,17 @@ var _ Evaluator = (*Equality)(nil)
 func (e *Equality) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Type, error) {
 	return WithInferErrorHandling(e, func() (hm.Type, error) {
 		// Type check both sides for validity, but allow cross-type comparison at runtime
-		_, err := e.Left.Infer(ctx, env, fresh)
+		lt, err := e.Left.Infer(ctx, env, fresh)
 		if err != nil {
 			return nil, err
 		}
-		_, err = e.Right.Infer(ctx, env, fresh)
+		rt, err := e.Right.Infer(ctx, env, fresh)
 		if err != nil {
 			return nil, err
 		}
+		if err := checkEnumStringComparison(lt, rt); err != nil {
+			return nil, err
+		}
 
 		// Equality always returns a boolean
 		return NonNullTypeNode{&NamedTypeNode{nil, "Boolean", e.Loc}}.Infer(ctx, env, fresh)
@@ -994,6 +1078,9 @@ func (u *UnaryMinus) Eval(ctx context.Context, scope ValueScope) (Value, error)
 
 		switch v := val.(type) {
 		case IntValue:
+			if v.Val == math.MinInt {
+				return nil, fmt.Errorf("%w: -(%d)", errIntOverflow, v.Val)
+			}
 			return IntValue{Val: -v.Val}, nil
 		case FloatValue:
 			return FloatValue{Val: -v.Val}, nil
@@ -1031,6 +1118,7 @@ func (n *NonNullAssert) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher)
 		// Strip the outer nullability. If the type is already non-null the
 		// assertion is a no-op and we keep the type as-is.
 		if nn, ok := t.(hm.NonNullType); ok {
+			EmitInferWarning(ctx, n, fmt.Sprintf("redundant `!`: the value is already non-null (%s), so the assertion can never fail", nn))
 			n.SetInferredType(nn)
 			return nn, nil
 		}
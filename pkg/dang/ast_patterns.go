@@ -3,6 +3,7 @@ package dang
 import (
 	"context"
 	"fmt"
+	"strings"
 
 	"github.com/vito/dang/v2/pkg/hm"
 )
@@ -62,6 +63,9 @@ func (c *Case) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Type
 		hasElse := false
 		var elseClause *CaseClause
 		var priorPatterns []*CaseClause
+		// matchedValues records the enum values named by value clauses, for
+		// the exhaustiveness warning below.
+		matchedValues := map[string]bool{}
 		var resultType hm.Type
 		for i, clause := range c.Clauses {
 			// A clause after an else catch-all can never match, whatever
@@ -104,6 +108,9 @@ func (c *Case) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Type
 				if err != nil {
 					return nil, WrapInferError(fmt.Errorf("Case.Infer: clause %d value type mismatch: %s != %s", i, exprType, valueType), clause.Value)
 				}
+				if name, ok := enumClauseValueName(clause.Value); ok {
+					matchedValues[name] = true
+				}
 				clause.Value = wrapCoerce(clause.Value, exprType, "")
 
 				caseType, err = WithInferErrorHandling(clause, func() (hm.Type, error) {
@@ -143,6 +150,13 @@ func (c *Case) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Type
 			resultType = nullableControlResultType(resultType)
 		}
 
+		if !hasElse && !c.NoOperand {
+			if missing := c.missingMembers(exprType, matchedValues); len(missing) > 0 {
+				EmitInferWarning(ctx, c, fmt.Sprintf("case over %s is not exhaustive; missing: %s",
+					unwrapNonNull(exprType), strings.Join(missing, ", ")))
+			}
+		}
+
 		return resultType, nil
 	})
 }
@@ -178,6 +192,80 @@ func (c *Case) coversAllMembers(exprType hm.Type) bool {
 	return false
 }
 
+// missingMembers lists what a case without an else leaves unmatched when
+// its operand is closed: the union members no type pattern covers, or the
+// enum values no value clause names. It returns nothing for open operands
+// (scalars, interfaces) and for cases that never target a member at all, so
+// only a case that tries to enumerate the variants gets warned about.
+func (c *Case) missingMembers(exprType hm.Type, matchedValues map[string]bool) []string {
+	var patterns []*Type
+	for _, clause := range c.Clauses {
+		if clause.IsTypePattern() && clause.resolvedMemberType != nil {
+			patterns = append(patterns, clause.resolvedMemberType)
+		}
+	}
+
+	var missing []string
+	switch t := unwrapNonNull(exprType).(type) {
+	case *Type:
+		switch t.Kind {
+		case UnionKind:
+			if len(patterns) == 0 {
+				return nil
+			}
+			for _, m := range t.GetMembers() {
+				if mod, ok := m.(*Type); ok && !moduleCoveredBy(mod, patterns) {
+					missing = append(missing, mod.Name())
+				}
+			}
+		case EnumKind:
+			if len(matchedValues) == 0 {
+				return nil
+			}
+			for _, name := range enumValueNames(t) {
+				if !matchedValues[name] {
+					missing = append(missing, name)
+				}
+			}
+		}
+	case *hm.UnionType:
+		if len(patterns) == 0 {
+			return nil
+		}
+		for _, option := range t.Options {
+			if mod, ok := unwrapNonNull(option).(*Type); ok && !moduleCoveredBy(mod, patterns) {
+				missing = append(missing, mod.Name())
+			}
+		}
+	}
+	return missing
+}
+
+// enumClauseValueName returns the enum value a case clause matches, for
+// clauses written as a qualified value (Color.RED) or a string literal
+// coerced to the enum ("RED").
+func enumClauseValueName(value Node) (string, bool) {
+	switch v := value.(type) {
+	case *Select:
+		return v.Field.Name, true
+	case *String:
+		return v.Value, true
+	}
+	return "", false
+}
+
+// enumValueNames returns the values of an enum type in declaration order,
+// skipping the values accessor that sits alongside them.
+func enumValueNames(enum *Type) []string {
+	var names []string
+	for name := range enum.Bindings(PublicVisibility) {
+		if enumHasValue(enum, name) {
+			names = append(names, name)
+		}
+	}
+	return names
+}
+
 func inlineUnionCoveredBy(union *hm.UnionType, patterns []*Type) bool {
 	for _, option := range union.Options {
 		nn, ok := option.(hm.NonNullType)
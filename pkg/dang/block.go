@@ -126,6 +126,17 @@ func classifyForms(forms []Node) ClassifiedForms {
 			}
 		case *FunDecl:
 			classified.Functions = append(classified.Functions, f)
+		case *SyntaxErrorNode:
+			// A broken declaration still declares its name, early enough for
+			// every other phase to see it.
+			switch {
+			case f.IsType:
+				classified.Types = append(classified.Types, f)
+			case f.Declares != "":
+				classified.Constants = append(classified.Constants, f)
+			default:
+				classified.NonDeclarations = append(classified.NonDeclarations, f)
+			}
 		default:
 			// All non-declarations (assignments, expressions, assertions, etc.)
 			classified.NonDeclarations = append(classified.NonDeclarations, form)
@@ -430,7 +441,11 @@ func (b *Block) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Typ
 
 		var typ hm.Type
 		var err error
+		declared := map[string]bool{}
 		for _, form := range forms {
+			if redeclaresLet(form, declared) {
+				newEnv = newEnv.Clone()
+			}
 			if inferer, ok := form.(hm.Inferer); ok {
 				typ, err = inferer.Infer(ctx, newEnv, fresh)
 				if err != nil {
@@ -450,6 +465,8 @@ func (b *Block) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Typ
 			return nil, errs
 		}
 
+		warnUnusedLets(ctx, b.Forms)
+
 		// Set inferred type only if we're able to fully infer
 		b.SetInferredType(typ)
 
@@ -467,7 +484,11 @@ func (b *Block) Eval(ctx context.Context, scope ValueScope) (Value, error) {
 
 	// Blocks evaluate forms in textual order
 	var result Value = NullValue{}
+	declared := map[string]bool{}
 	for _, form := range forms {
+		if redeclaresLet(form, declared) {
+			newScope = newScope.Derive(false)
+		}
 		val, err := EvalNode(ctx, newScope, form)
 		if err != nil {
 			return nil, err
@@ -477,6 +498,23 @@ func (b *Block) Eval(ctx context.Context, scope ValueScope) (Value, error) {
 	return result, nil
 }
 
+// redeclaresLet reports whether form declares a name that an earlier form of
+// the same block declared, recording the name in declared. Such a `let`
+// shadows the earlier binding for the rest of the block, so it gets a scope of
+// its own instead of colliding with it; anything that captured the earlier
+// binding keeps seeing it.
+func redeclaresLet(form Node, declared map[string]bool) bool {
+	decl, ok := form.(*FieldDecl)
+	if !ok {
+		return false
+	}
+	if declared[decl.Name.Name] {
+		return true
+	}
+	declared[decl.Name.Name] = true
+	return false
+}
+
 func (b *Block) Walk(fn func(Node) bool) {
 	if !fn(b) {
 		return
@@ -465,6 +465,14 @@ func (b *StaticMethodBuilder) Replacement(name string) *StaticMethodBuilder {
 	return b
 }
 
+// Numeric marks the static method's type-variable parameters as taking an
+// Int or a Float. Inference rejects any other argument, and a type variable
+// return becomes Float when the arguments mix Int and Float.
+func (b *StaticMethodBuilder) Numeric() *StaticMethodBuilder {
+	b.def.Numeric = true
+	return b
+}
+
 // Impl sets the implementation and registers the static method
 func (b *StaticMethodBuilder) Impl(fn func(context.Context, Args) (Value, error)) {
 	b.def.Impl = func(ctx context.Context, self Value, args Args) (Value, error) {
@@ -498,6 +506,9 @@ type BuiltinDef struct {
 	// (e.g. "JSON.encode"). Unlike the prose in Deprecated, it is structured so
 	// tools can rewrite a call mechanically — the LSP offers it as a quick fix.
 	Replacement string
+	// Numeric marks a builtin whose type-variable parameters only accept Int
+	// or Float; see FunCall.checkNumericArguments.
+	Numeric bool
 }
 
 // ParamDef defines a parameter with optional default value.
@@ -582,6 +593,16 @@ func LookupMethod(receiverType *Type, methodName string) (BuiltinDef, bool) {
 	return def, ok
 }
 
+// LookupStaticMethod finds a static method registered on a module.
+func LookupStaticMethod(hostModule *Type, name string) (BuiltinDef, bool) {
+	for _, def := range builtins.staticMethods[hostModule] {
+		if def.Name == name {
+			return def, true
+		}
+	}
+	return BuiltinDef{}, false
+}
+
 // GetMethodKey returns the environment key for a method.
 func GetMethodKey(receiverType *Type, methodName string) string {
 	return fmt.Sprintf("_%s_%s_builtin",
@@ -0,0 +1,146 @@
+package dang
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+// Check type-checks the .dang file or directory module at path without
+// evaluating it. It returns the same errors RunFile or RunDir would report
+// before evaluation starts.
+func Check(ctx context.Context, path string) error {
+	_, err := checkModule(ctx, path)
+	return err
+}
+
+// checkModule type-checks path like Check, returning the inferred files.
+func checkModule(ctx context.Context, path string) ([]*FileBlock, error) {
+	ctx, services := ensureServiceRegistry(ctx)
+	if services != nil {
+		defer services.StopAll()
+	}
+
+	info, err := os.Stat(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to access path %s: %w", path, err)
+	}
+
+	if info.IsDir() {
+		ctx, blocks, err := parseDirBlocks(ctx, path)
+		if err != nil {
+			return nil, err
+		}
+		if len(blocks) == 0 {
+			return nil, fmt.Errorf("no .dang files found in directory: %s", path)
+		}
+		ctx = withModuleScope(ctx, &moduleScope{label: "dir:" + path})
+		if err := InferDirectoryFiles(ctx, blocks, NewPreludeTypeScope(""), hm.NewSimpleFresher()); err != nil {
+			return nil, ConvertInferError(err)
+		}
+		return blocks, nil
+	}
+
+	ctx, err = ensureProjectImports(ctx, filepath.Dir(path))
+	if err != nil {
+		var sourceErr *SourceError
+		if errors.As(err, &sourceErr) {
+			return nil, err
+		}
+		return nil, fmt.Errorf("loading project config: %w", err)
+	}
+	parsed, err := ParseFileWithRecovery(path, GlobalStore("filePath", path))
+	if err != nil {
+		return nil, err
+	}
+	node := parsed.(*FileBlock)
+	node.Forms = InjectAutoImports(ctx, node.Forms)
+	ctx = withModuleScope(ctx, &moduleScope{label: "file:" + path})
+	if _, err := Infer(ctx, NewPreludeTypeScope(""), node, true); err != nil {
+		return nil, ConvertInferError(err)
+	}
+	return []*FileBlock{node}, nil
+}
+
+// Diagnostic is a machine-readable form of one error reported by Check.
+// Lines and columns are 1-based, as in rendered errors; the end column is
+// exclusive. They are zero when the error has no source location.
+type Diagnostic struct {
+	File        string `json:"file"`
+	StartLine   int    `json:"startLine"`
+	StartColumn int    `json:"startColumn"`
+	EndLine     int    `json:"endLine"`
+	EndColumn   int    `json:"endColumn"`
+	Severity    string `json:"severity"`
+	Message     string `json:"message"`
+	// Suggestion holds any notes following the message, e.g. "did you mean
+	// `image`?".
+	Suggestion string `json:"suggestion,omitempty"`
+}
+
+// Diagnostics breaks an error returned by Check into one Diagnostic per
+// underlying error.
+func Diagnostics(err error) []Diagnostic {
+	if err == nil {
+		return nil
+	}
+
+	var inferErrs *InferenceErrors
+	if errors.As(err, &inferErrs) {
+		var ds []Diagnostic
+		for _, e := range inferErrs.Errors {
+			ds = append(ds, Diagnostics(e)...)
+		}
+		return ds
+	}
+
+	d := Diagnostic{Severity: "error"}
+	message := err.Error()
+
+	var loc *SourceLocation
+	var sourceErr *SourceError
+	var parseErr interface {
+		ParseErrorLocation() *SourceLocation
+	}
+	var inferErr *InferError
+	switch {
+	case errors.As(err, &sourceErr):
+		loc = sourceErr.Location
+		// The inner message, rather than the rendered source snippet.
+		message = sourceErr.Inner.Error()
+	case errors.As(err, &parseErr):
+		loc = parseErr.ParseErrorLocation()
+	case errors.As(err, &inferErr):
+		loc = inferErr.Location
+		message = inferErr.Inner.Error()
+	}
+
+	if loc != nil {
+		d.File = loc.Filename
+		d.StartLine = loc.Line
+		d.StartColumn = loc.Column
+		d.EndLine = loc.Line
+		d.EndColumn = loc.Column + max(1, loc.Length)
+		if loc.End != nil {
+			d.EndLine = loc.End.Line
+			d.EndColumn = loc.End.Column
+		}
+	}
+
+	// Notes are appended to the message as indented lines.
+	first, notes, _ := strings.Cut(message, "\n")
+	d.Message = first
+	var suggestion []string
+	for note := range strings.SplitSeq(notes, "\n") {
+		if note = strings.TrimSpace(note); note != "" {
+			suggestion = append(suggestion, note)
+		}
+	}
+	d.Suggestion = strings.Join(suggestion, "\n")
+	return []Diagnostic{d}
+}
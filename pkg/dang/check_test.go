@@ -0,0 +1,54 @@
+package dang
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestCheckDiagnostics(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "main.dang")
+	require.NoError(t, os.WriteFile(path, []byte(`type Image {
+  image: String! = "alpine"
+}
+
+let img = Image
+img.imagee
+`), 0o600))
+
+	err := Check(context.Background(), dir)
+	require.Error(t, err)
+
+	diags := Diagnostics(err)
+	require.Len(t, diags, 1)
+	require.Equal(t, Diagnostic{
+		File:        path,
+		StartLine:   6,
+		StartColumn: 1,
+		EndLine:     6,
+		EndColumn:   11,
+		Severity:    "error",
+		Message:     `field "imagee" not found in Image`,
+		Suggestion:  "did you mean `image`?",
+	}, diags[0])
+
+	require.NoError(t, os.WriteFile(path, []byte("pub x = 1\n"), 0o600))
+	require.NoError(t, Check(context.Background(), dir))
+	require.NoError(t, Check(context.Background(), path))
+}
+
+func TestCheckInvalidRegexpLiteral(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "main.dang")
+	require.NoError(t, os.WriteFile(path, []byte("pub ok = \"v1.2\".containsMatch(`(`)\n"), 0o600))
+
+	diags := Diagnostics(Check(context.Background(), path))
+	require.Len(t, diags, 1)
+	require.Equal(t, 1, diags[0].StartLine)
+	require.Equal(t, 31, diags[0].StartColumn)
+	require.Contains(t, diags[0].Message, `invalid regexp "("`)
+}
@@ -2,6 +2,7 @@ package dang
 
 import (
 	"context"
+	"fmt"
 	"strings"
 
 	"github.com/vito/dang/v2/pkg/hm"
@@ -11,7 +12,8 @@ import (
 type CompletionContext struct {
 	Kind         ContextKind
 	ReceiverText string   // for DotMember: normalized receiver source
-	FuncText     string   // for Argument: normalized function expression
+	FuncText     string   // for Argument and ArgumentValue: normalized function expression
+	ArgName      string   // for ArgumentValue: the named argument being given
 	Partial      string   // prefix the user is typing
 	ProvidedArgs []string // for Argument: already-present named args
 }
@@ -20,10 +22,11 @@ type CompletionContext struct {
 type ContextKind int
 
 const (
-	ContextNone      ContextKind = iota
-	ContextDotMember             // ctr.fr|  or  ctr.|
-	ContextArgument              // container.from(addr|
-	ContextBareIdent             // ct|  (no dot, no parens)
+	ContextNone          ContextKind = iota
+	ContextDotMember                 // ctr.fr|  or  ctr.|
+	ContextArgument                  // container.from(addr|
+	ContextBareIdent                 // ct|  (no dot, no parens)
+	ContextArgumentValue             // withMountedCache(sharing: SH|
 )
 
 // CompletionResult holds the completions and where to start replacing.
@@ -39,12 +42,30 @@ type Completion struct {
 	Documentation string // Longer doc string
 	IsFunction    bool   // Whether this is a function/method
 	IsArg         bool   // Whether this is a function argument
+	Snippet       string // Call template with tab stops for required args, if any
 }
 
 func isIdentByte(c byte) bool {
 	return (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || (c >= '0' && c <= '9') || c == '_'
 }
 
+// lineColToOffset converts a 0-based line/col to a byte offset in source.
+func lineColToOffset(source []byte, line, col uint) int {
+	offset := 0
+	currentLine := uint(0)
+	for offset < len(source) && currentLine < line {
+		if source[offset] == '\n' {
+			currentLine++
+		}
+		offset++
+	}
+	offset += int(col)
+	if offset > len(source) {
+		offset = len(source)
+	}
+	return offset
+}
+
 // completeMember tries to parse and type-check the receiver expression, then
 // returns completions for its members filtered by the partial name.
 func completeMember(ctx context.Context, typeScope TypeScope, receiverText, partial string) []Completion {
@@ -133,6 +154,60 @@ func ArgsOf(t hm.Type, partial string, alreadyProvided []string) []Completion {
 	return completions
 }
 
+// completeArgValues returns completions for the value of the named argument
+// argName of the function expression funcExpr.
+func completeArgValues(ctx context.Context, typeScope TypeScope, funcExpr, argName, partial string) []Completion {
+	t := InferReceiverType(ctx, typeScope, funcExpr)
+	if t == nil {
+		return nil
+	}
+	return ArgValuesOf(t, argName, partial)
+}
+
+// ArgValuesOf returns completions for the value of the argument argName of a
+// function type, filtered by the partial prefix. An enum-typed argument
+// completes to the enum's values; other arguments have no value completions.
+func ArgValuesOf(t hm.Type, argName, partial string) []Completion {
+	if nn, ok := t.(hm.NonNullType); ok {
+		t = nn.Type
+	}
+	ft, ok := t.(*hm.FunctionType)
+	if !ok {
+		return nil
+	}
+	record, ok := unwrapNonNull(ft.Arg()).(*RecordType)
+	if !ok {
+		return nil
+	}
+	scheme, found := record.SchemeOf(argName)
+	if !found {
+		return nil
+	}
+	argType, _ := scheme.Type()
+	enum, ok := unwrapNonNull(argType).(*Type)
+	if !ok || enum.Kind != EnumKind {
+		return nil
+	}
+
+	partialLower := strings.ToLower(partial)
+	var completions []Completion
+	for _, name := range enumValueNames(enum) {
+		label := enum.Name() + "." + name
+		if partial != "" &&
+			!strings.HasPrefix(strings.ToLower(name), partialLower) &&
+			!strings.HasPrefix(strings.ToLower(label), partialLower) {
+			continue
+		}
+		doc, _ := enum.GetDocString(name)
+		completions = append(completions, Completion{
+			Label:         label,
+			Detail:        enum.Name() + "!",
+			Documentation: doc,
+		})
+	}
+	return completions
+}
+
 // InferReceiverType parses and type-checks a receiver expression, returning
 // its inferred type. Returns nil if parsing or inference fails.
 func InferReceiverType(ctx context.Context, typeScope TypeScope, expr string) hm.Type {
@@ -204,12 +279,59 @@ func MembersOf(t hm.Type, partial string) []Completion {
 			Detail:        memberType.String(),
 			Documentation: doc,
 			IsFunction:    isFn,
+			Snippet:       CallSnippet(name, memberType),
 		})
 	}
 
 	return completions
 }
 
+// CallSnippet returns an LSP snippet that calls the function t by name with
+// a tab stop for each required argument, e.g. `from(address: ${1:String!})`.
+// It returns "" when t isn't a function or has no required arguments, since
+// such a function auto-calls and the bare name is all there is to insert.
+func CallSnippet(name string, t hm.Type) string {
+	if nn, ok := t.(hm.NonNullType); ok {
+		t = nn.Type
+	}
+	ft, ok := t.(*hm.FunctionType)
+	if !ok {
+		return ""
+	}
+	argType := ft.Arg()
+	if nn, ok := argType.(hm.NonNullType); ok {
+		argType = nn.Type
+	}
+	record, ok := argType.(*RecordType)
+	if !ok {
+		return ""
+	}
+
+	var args []string
+	for _, field := range record.Fields {
+		fieldType, mono := field.Value.Type()
+		if !mono {
+			continue
+		}
+		// Arguments with defaults are nullable in the signature, so only
+		// non-null ones are required.
+		if _, required := fieldType.(hm.NonNullType); !required {
+			continue
+		}
+		args = append(args, fmt.Sprintf("%s: ${%d:%s}", field.Key, len(args)+1, escapeSnippet(fieldType.String())))
+	}
+	if len(args) == 0 {
+		return ""
+	}
+	return name + "(" + strings.Join(args, ", ") + ")$0"
+}
+
+// escapeSnippet escapes the characters that are special inside an LSP
+// snippet placeholder.
+func escapeSnippet(s string) string {
+	return strings.NewReplacer(`\`, `\\`, `$`, `\$`, `}`, `\}`).Replace(s)
+}
+
 // builtinModuleFor returns the builtin method module for a primitive or list
 // type, or nil if the type has no builtin methods.
 func builtinModuleFor(t hm.Type) *Type {
@@ -260,6 +382,7 @@ func completeLexical(typeScope TypeScope, prefix string) []Completion {
 			Detail:        memberType.String(),
 			Documentation: doc,
 			IsFunction:    isFn,
+			Snippet:       CallSnippet(name, memberType),
 		})
 	}
 
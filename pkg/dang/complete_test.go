@@ -157,3 +157,37 @@ func TestArgsOf(t *testing.T) {
 		}
 	})
 }
+
+func TestCallSnippet(t *testing.T) {
+	containerType := NewType("Container", ObjectKind)
+
+	t.Run("required args get tab stops", func(t *testing.T) {
+		args := NewRecordType("")
+		args.Add("address", hm.NewScheme(nil, hm.NonNullType{Type: StringType}))
+		args.Add("platform", hm.NewScheme(nil, StringType))
+		args.Add("retries", hm.NewScheme(nil, hm.NonNullType{Type: IntType}))
+		fnType := hm.NewFnType(args, containerType)
+
+		got := CallSnippet("from", fnType)
+		want := "from(address: ${1:String!}, retries: ${2:Int!})$0"
+		if got != want {
+			t.Errorf("got %q, want %q", got, want)
+		}
+	})
+
+	t.Run("no required args", func(t *testing.T) {
+		args := NewRecordType("")
+		args.Add("platform", hm.NewScheme(nil, StringType))
+		fnType := hm.NewFnType(args, containerType)
+
+		if got := CallSnippet("sync", fnType); got != "" {
+			t.Errorf("got %q, want no snippet", got)
+		}
+	})
+
+	t.Run("not a function", func(t *testing.T) {
+		if got := CallSnippet("name", hm.NonNullType{Type: StringType}); got != "" {
+			t.Errorf("got %q, want no snippet", got)
+		}
+	})
+}
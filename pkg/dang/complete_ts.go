@@ -58,6 +58,8 @@ func Complete(ctx context.Context, env TypeScope, text string, line, col int) Co
 		items = completeMember(ctx, env, cc.ReceiverText, cc.Partial)
 	case ContextArgument:
 		items = completeArgs(ctx, env, cc.FuncText, cc.Partial, cc.ProvidedArgs)
+	case ContextArgumentValue:
+		items = completeArgValues(ctx, env, cc.FuncText, cc.ArgName, cc.Partial)
 	case ContextBareIdent:
 		items = completeLexical(env, cc.Partial)
 	}
@@ -159,6 +161,15 @@ func findArgContextAtCursor(root *tree_sitter.Node, source []byte, line, col uin
 // that tree-sitter successfully parsed (e.g. "foo(" with empty args, or
 // "foo(x, " with trailing comma).
 func findArgInArgValues(root *tree_sitter.Node, source []byte, line, col uint) *CompletionContext {
+	if argValues := argValuesAtCursor(root, line, col); argValues != nil {
+		return buildArgContextFromArgValues(argValues, source, line, col)
+	}
+	return nil
+}
+
+// argValuesAtCursor returns the innermost arg_values node containing the
+// cursor, or one the cursor sits just past the trailing comma of.
+func argValuesAtCursor(root *tree_sitter.Node, line, col uint) *tree_sitter.Node {
 	var deepest *tree_sitter.Node
 	walkTS(root, func(n *tree_sitter.Node) bool {
 		if !tsContains(n.StartPosition(), n.EndPosition(), line, col) {
@@ -175,7 +186,7 @@ func findArgInArgValues(root *tree_sitter.Node, source []byte, line, col uint) *
 	node := deepest
 	for node != nil {
 		if node.Kind() == "arg_values" {
-			return buildArgContextFromArgValues(node, source, line, col)
+			return node
 		}
 		node = node.Parent()
 	}
@@ -197,11 +208,9 @@ func findArgInArgValues(root *tree_sitter.Node, source []byte, line, col uint) *
 		}
 		return true
 	})
-	if bestArgValues != nil {
-		// Verify the arg_values has a trailing sep (comma) as last real child.
-		if hasTrailingSep(bestArgValues) {
-			return buildArgContextFromArgValues(bestArgValues, source, line, col)
-		}
+	// Verify the arg_values has a trailing sep (comma) as last real child.
+	if bestArgValues != nil && hasTrailingSep(bestArgValues) {
+		return bestArgValues
 	}
 
 	return nil
@@ -239,16 +248,12 @@ func buildArgContextFromArgValues(argValues *tree_sitter.Node, source []byte, li
 		return nil
 	}
 
-	// Check value position: cursor inside a key_value's value field.
-	if isInValuePosition(argValues, source, line, col) {
-		return nil
-	}
-
 	partial := extractPartialAtCursor(source, line, col)
 
-	// Check colon-based value position for partial typing after "name:".
-	if isAfterColon(source, line, col, partial) {
-		return nil
+	// Check value position: cursor inside a key_value's value field, or
+	// partial typing after "name:".
+	if isInValuePosition(argValues, source, line, col) || isAfterColon(source, line, col, partial) {
+		return argValueContext(source, line, col, funcText, partial)
 	}
 
 	providedArgs := collectProvidedArgs(argValues, source)
@@ -270,38 +275,12 @@ func buildArgContextFromArgValues(argValues *tree_sitter.Node, source []byte, li
 // Pattern C: ERROR contains everything — function, paren, and args as flat
 // children (e.g. "foo(na" or "foo(name: x, addr").
 func findArgInError(root *tree_sitter.Node, source []byte, line, col uint) *CompletionContext {
-	// Find ERROR nodes containing the cursor that have immediate_paren children.
-	var bestError *tree_sitter.Node
-	walkTS(root, func(n *tree_sitter.Node) bool {
-		if !tsContains(n.StartPosition(), n.EndPosition(), line, col) {
-			return false
-		}
-		if n.Kind() == "ERROR" && hasChildKind(n, "immediate_paren") {
-			bestError = n
-		}
-		return true
-	})
-
+	bestError, parenIdx := openParenErrorAtCursor(root, line, col)
 	if bestError == nil {
 		return nil
 	}
 
-	// Find the innermost unmatched immediate_paren by scanning children
-	// right-to-left, tracking paren depth.
-	parenIdx := findInnermostOpenParen(bestError, line, col)
-	if parenIdx < 0 {
-		return nil
-	}
-
-	// Reconstruct the function expression from children before the paren.
-	funcText := reconstructFuncFromError(bestError, parenIdx, source)
-	if funcText == "" {
-		// Pattern B: the ERROR's preceding sibling is the function.
-		parent := bestError.Parent()
-		if parent != nil {
-			funcText = extractFuncFromPrecedingSibling(parent, bestError, source)
-		}
-	}
+	funcText := funcTextFromError(bestError, parenIdx, source)
 	if funcText == "" {
 		return nil
 	}
@@ -313,14 +292,10 @@ func findArgInError(root *tree_sitter.Node, source []byte, line, col uint) *Comp
 
 	partial := extractPartialAtCursor(source, line, col)
 
-	// Check value position: after a colon_token.
-	if isAfterColon(source, line, col, partial) {
-		return nil
-	}
-
-	// Check value position: inside a key_value value field.
-	if isInKeyValueValuePosition(bestError, source, line, col) {
-		return nil
+	// Check value position: after a colon_token, or inside a key_value value
+	// field.
+	if isAfterColon(source, line, col, partial) || isInKeyValueValuePosition(bestError, source, line, col) {
+		return argValueContext(source, line, col, funcText, partial)
 	}
 
 	// Collect already-provided argument names.
@@ -334,6 +309,49 @@ func findArgInError(root *tree_sitter.Node, source []byte, line, col uint) *Comp
 	}
 }
 
+// openParenErrorAtCursor returns the innermost ERROR node containing the
+// cursor along with the index of its innermost immediate_paren left open
+// before the cursor, or nil if there is none.
+func openParenErrorAtCursor(root *tree_sitter.Node, line, col uint) (*tree_sitter.Node, int) {
+	// Find ERROR nodes containing the cursor that have immediate_paren children.
+	var bestError *tree_sitter.Node
+	walkTS(root, func(n *tree_sitter.Node) bool {
+		if !tsContains(n.StartPosition(), n.EndPosition(), line, col) {
+			return false
+		}
+		if n.Kind() == "ERROR" && hasChildKind(n, "immediate_paren") {
+			bestError = n
+		}
+		return true
+	})
+
+	if bestError == nil {
+		return nil, -1
+	}
+
+	// Find the innermost unmatched immediate_paren by scanning children
+	// right-to-left, tracking paren depth.
+	parenIdx := findInnermostOpenParen(bestError, line, col)
+	if parenIdx < 0 {
+		return nil, -1
+	}
+	return bestError, parenIdx
+}
+
+// funcTextFromError returns the function expression called by the paren at
+// parenIdx in errorNode, or "" if it can't be found.
+func funcTextFromError(errorNode *tree_sitter.Node, parenIdx int, source []byte) string {
+	// Reconstruct the function expression from children before the paren.
+	funcText := reconstructFuncFromError(errorNode, parenIdx, source)
+	if funcText == "" {
+		// Pattern B: the ERROR's preceding sibling is the function.
+		if parent := errorNode.Parent(); parent != nil {
+			funcText = extractFuncFromPrecedingSibling(parent, errorNode, source)
+		}
+	}
+	return funcText
+}
+
 // extractFuncText extracts the function expression text from a call or
 // select_or_call node.
 func extractFuncText(callNode *tree_sitter.Node, source []byte) string {
@@ -557,6 +575,33 @@ func isAfterColon(source []byte, line, col uint, partial string) bool {
 	return j >= 0 && source[j] == ':'
 }
 
+// argValueContext builds the completion context for the value of a named
+// argument, when the cursor sits directly after "name:". Anything else in
+// value position (e.g. "name: Status.AC") is left to the other strategies.
+func argValueContext(source []byte, line, col uint, funcText, partial string) *CompletionContext {
+	offset := lineColToOffset(source, line, col)
+	j := offset - len(partial) - 1
+	for j >= 0 && (source[j] == ' ' || source[j] == '\t' || source[j] == '\n') {
+		j--
+	}
+	if j < 0 || source[j] != ':' {
+		return nil
+	}
+	end := j
+	for j > 0 && isIdentByte(source[j-1]) {
+		j--
+	}
+	if j == end {
+		return nil
+	}
+	return &CompletionContext{
+		Kind:     ContextArgumentValue,
+		FuncText: funcText,
+		ArgName:  string(source[j:end]),
+		Partial:  partial,
+	}
+}
+
 // collectProvidedArgs extracts already-provided argument names from key_value
 // children of an arg_values node.
 func collectProvidedArgs(argValues *tree_sitter.Node, source []byte) []string {
@@ -748,23 +793,6 @@ func extractPartialAtCursor(source []byte, line, col uint) string {
 	return string(source[i+1 : offset])
 }
 
-// lineColToOffset converts a 0-based line/col to a byte offset in source.
-func lineColToOffset(source []byte, line, col uint) int {
-	offset := 0
-	currentLine := uint(0)
-	for offset < len(source) && currentLine < line {
-		if source[offset] == '\n' {
-			currentLine++
-		}
-		offset++
-	}
-	offset += int(col)
-	if offset > len(source) {
-		offset = len(source)
-	}
-	return offset
-}
-
 // findBareIdentAtCursor checks if the cursor is on a bare identifier (not
 // inside a dot expression or argument list).
 func findBareIdentAtCursor(root *tree_sitter.Node, source []byte, line, col uint) string {
@@ -35,6 +35,18 @@ func testCompletionTypeScope() TypeScope {
 	containerMod.Add("withExec", hm.NewScheme(nil, hm.NewFnType(execArgs, containerMod)))
 	containerMod.SetVisibility("withExec", PublicVisibility)
 
+	// withMountedCache(path: String!, sharing: CacheSharingMode) -> Container
+	sharingMod := NewType("CacheSharingMode", EnumKind)
+	for _, value := range []string{"SHARED", "PRIVATE", "LOCKED"} {
+		sharingMod.Add(value, hm.NewScheme(nil, hm.NonNullType{Type: sharingMod}))
+		sharingMod.SetVisibility(value, PublicVisibility)
+	}
+	cacheArgs := NewRecordType("")
+	cacheArgs.Add("path", hm.NewScheme(nil, hm.NonNullType{Type: StringType}))
+	cacheArgs.Add("sharing", hm.NewScheme(nil, sharingMod))
+	containerMod.Add("withMountedCache", hm.NewScheme(nil, hm.NewFnType(cacheArgs, containerMod)))
+	containerMod.SetVisibility("withMountedCache", PublicVisibility)
+
 	env.Add("container", hm.NewScheme(nil, hm.NonNullType{Type: containerMod}))
 	env.SetVisibility("container", PublicVisibility)
 	env.AddObject("Container", containerMod)
@@ -249,6 +261,29 @@ func TestComplete_ArgCompletion(t *testing.T) {
 			wantNone: true,
 		},
 
+		// Value position of an enum argument — suggest its values
+		{
+			name:       "enum values after colon",
+			text:       "container.withMountedCache(sharing: ",
+			line:       0,
+			col:        36,
+			wantLabels: []string{"CacheSharingMode.SHARED", "CacheSharingMode.PRIVATE", "CacheSharingMode.LOCKED"},
+		},
+		{
+			name:       "partial enum value",
+			text:       "container.withMountedCache(path: \"/cache\", sharing: SH",
+			line:       0,
+			col:        54,
+			wantLabels: []string{"CacheSharingMode.SHARED"},
+		},
+		{
+			name:     "no values for a non-enum argument",
+			text:     "container.withMountedCache(path: ",
+			line:     0,
+			col:      33,
+			wantNone: true,
+		},
+
 		// Inside brackets — should NOT suggest args
 		{
 			name:     "inside brackets",
@@ -0,0 +1,178 @@
+package dang
+
+import (
+	"errors"
+	"fmt"
+	"math"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+// errIntOverflow is wrapped by every Int arithmetic overflow, so constant
+// folding can tell an overflow apart from other runtime failures.
+var errIntOverflow = errors.New("integer overflow")
+
+// constantValue evaluates node while type checking, if it's built only from
+// literals and arithmetic over them, e.g. `8000 + 80` or `-1`. ok is false for
+// anything else; the runtime evaluator handles those as usual.
+//
+// Folding runs each operator's own evaluator, so a constant expression
+// computes exactly what it would at runtime. An overflow is returned as err.
+// Any other failure, such as a division by zero, isn't folded, and stays a
+// runtime error that can be rescued.
+func constantValue(node Node) (val Value, ok bool, err error) {
+	switch n := node.(type) {
+	case *Int:
+		return IntValue{Val: int(n.Value)}, true, nil
+	case *Float:
+		return FloatValue{Val: n.Value}, true, nil
+	case *Boolean:
+		return BoolValue{Val: n.Value}, true, nil
+	case *String:
+		return StringValue{Val: n.Value}, true, nil
+	case *Template:
+		if !n.IsLiteralOnly() {
+			return nil, false, nil
+		}
+		var sb strings.Builder
+		for _, p := range n.Parts {
+			sb.WriteString(p.Lit)
+		}
+		return StringValue{Val: sb.String()}, true, nil
+	case *Grouped:
+		return constantValue(n.Expr)
+	case *Coerce:
+		return constantValue(n.Expr)
+	case *UnaryMinus:
+		v, ok, err := constantValue(n.Expr)
+		if !ok || err != nil {
+			return nil, ok, err
+		}
+		switch v := v.(type) {
+		case IntValue:
+			if v.Val == math.MinInt {
+				return nil, true, fmt.Errorf("%w: -(%d)", errIntOverflow, v.Val)
+			}
+			return IntValue{Val: -v.Val}, true, nil
+		case FloatValue:
+			return FloatValue{Val: -v.Val}, true, nil
+		}
+	case *Addition:
+		return n.constantValue()
+	case *Subtraction:
+		return n.constantValue()
+	case *Multiplication:
+		return n.constantValue()
+	case *Division:
+		return n.constantValue()
+	case *Modulo:
+		return n.constantValue()
+	}
+	return nil, false, nil
+}
+
+// constantValue folds an arithmetic operator over constant operands.
+func (b *BinaryOperator) constantValue() (Value, bool, error) {
+	l, ok, err := constantValue(b.Left)
+	if !ok || err != nil {
+		return nil, ok, err
+	}
+	r, ok, err := constantValue(b.Right)
+	if !ok || err != nil {
+		return nil, ok, err
+	}
+	v, err := b.EvalFunc(l, r)
+	if err != nil {
+		if errors.Is(err, errIntOverflow) {
+			return nil, true, err
+		}
+		return nil, false, nil
+	}
+	return v, true, nil
+}
+
+// checkConstantArgument validates a constant argument whose valid values are
+// narrower than its type: a port must be in range, a duration string must
+// parse, and a Platform literal must look like `os/arch`. These are the
+// common Dagger arguments written as literals, and getting one wrong would
+// otherwise only fail once the call runs, typically deep into a build.
+//
+// Ports and durations are recognized by the parameter name, e.g. `port`,
+// `frontendPort`, or `timeout`, and only for schemaField calls; a Dang
+// function is free to give its own `port: 0` or `timeout: "5 minutes"` a
+// meaning. Platform is recognized by its schema scalar type.
+func checkConstantArgument(node Node, target hm.Type, key string, schemaField bool) error {
+	val, ok, err := constantValue(node)
+	if !ok || err != nil {
+		// Overflows are reported by the operator itself while it's inferred.
+		return nil
+	}
+	switch val := val.(type) {
+	case IntValue:
+		if schemaField && isPortParam(key) && (val.Val < 1 || val.Val > 65535) {
+			return materializeError(key, "%d is out of range; ports are between 1 and 65535", val.Val)
+		}
+	case StringValue:
+		if isPlatformType(target) {
+			if !platformPattern.MatchString(val.Val) {
+				return materializeError(key, "invalid platform %q; expected os/arch or os/arch/variant, e.g. \"linux/amd64\"", val.Val)
+			}
+			return nil
+		}
+		if schemaField && isDurationParam(key) && isStringType(target) {
+			if _, err := time.ParseDuration(val.Val); err != nil {
+				return materializeError(key, "invalid duration %q; expected a number with a unit, e.g. \"30s\" or \"1m30s\"", val.Val)
+			}
+		}
+	}
+	return nil
+}
+
+// callsSchemaField reports whether the call's callee, of type ft, is a field
+// of an imported schema rather than a Dang function. Like the rescue laziness
+// analysis, a bare name is only recognized as a root field when it returns a
+// schema object.
+func (c *FunCall) callsSchemaField(ft *hm.FunctionType) bool {
+	switch fn := c.Fun.(type) {
+	case *Select:
+		return classifyGQLSelect(fn) != notGQLField
+	case *Symbol:
+		mod := schemaObjectModule(ft.Ret(false))
+		if mod == nil || mod.SourceSchema.QueryType.Name == "" {
+			return false
+		}
+		return classifyField(mod.SourceSchema, mod.SourceSchema.QueryType.Name, fn.Name) != notGQLField
+	}
+	return false
+}
+
+func isPortParam(key string) bool {
+	return key == "port" || strings.HasSuffix(key, "Port")
+}
+
+func isDurationParam(key string) bool {
+	for _, name := range []string{"timeout", "duration", "interval"} {
+		if key == name || strings.HasSuffix(key, strings.ToUpper(name[:1])+name[1:]) {
+			return true
+		}
+	}
+	return false
+}
+
+func isStringType(t hm.Type) bool {
+	return unwrapNonNull(t) == StringType
+}
+
+// platformPattern matches an OCI platform string: up to three
+// slash-separated, non-empty components.
+var platformPattern = regexp.MustCompile(`^[A-Za-z0-9_.-]+(/[A-Za-z0-9_.-]+){0,2}$`)
+
+// isPlatformType reports whether t is Dagger's Platform scalar, as imported
+// from a schema. A user type that happens to share the name doesn't count.
+func isPlatformType(t hm.Type) bool {
+	mod, ok := unwrapNonNull(t).(*Type)
+	return ok && mod.Named == "Platform" && mod.Kind == ScalarKind && mod.SourceSchema != nil
+}
@@ -0,0 +1,155 @@
+package dang
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+	"github.com/vito/dang/v2/pkg/hm"
+	"github.com/vito/dang/v2/pkg/introspection"
+)
+
+func parseExpr(t *testing.T, src string) Node {
+	t.Helper()
+	parsed, err := ParseWithRecovery("constfold", []byte(src))
+	require.NoError(t, err)
+	file, ok := parsed.(*FileBlock)
+	require.True(t, ok, "unexpected parse result %T", parsed)
+	require.Len(t, file.Forms, 1)
+	return file.Forms[0]
+}
+
+func TestConstantValue(t *testing.T) {
+	for _, example := range []struct {
+		src      string
+		expected Value
+		overflow bool
+	}{
+		{src: `8000 + 80`, expected: IntValue{Val: 8080}},
+		{src: `-(2 * 3)`, expected: IntValue{Val: -6}},
+		{src: `7 % 4`, expected: IntValue{Val: 3}},
+		{src: `1.5 + 1`, expected: FloatValue{Val: 2.5}},
+		{src: `"30" + "s"`, expected: StringValue{Val: "30s"}},
+		{src: `9223372036854775807 + 1`, overflow: true},
+		{src: `(9223372036854775807 * 2) - 1`, overflow: true},
+		// Not folded: these stay runtime errors.
+		{src: `1 / 0`},
+		{src: `x + 1`},
+	} {
+		t.Run(example.src, func(t *testing.T) {
+			val, ok, err := constantValue(parseExpr(t, example.src))
+			if example.overflow {
+				require.ErrorIs(t, err, errIntOverflow)
+				return
+			}
+			require.NoError(t, err)
+			if example.expected == nil {
+				require.False(t, ok)
+				return
+			}
+			require.True(t, ok)
+			require.Equal(t, example.expected, val)
+		})
+	}
+}
+
+func TestCheckConstantArgument(t *testing.T) {
+	platform := NewType("Platform", ScalarKind)
+	platform.SourceSchema = &introspection.Schema{}
+
+	for _, example := range []struct {
+		src    string
+		target hm.Type
+		key    string
+		dangFn bool // the callee is a Dang function, not a schema field
+		err    string
+	}{
+		{src: `8080`, target: NonNull(IntType), key: "port"},
+		{src: `0`, target: NonNull(IntType), key: "port", err: "port: 0 is out of range"},
+		{src: `70000`, target: NonNull(IntType), key: "frontendPort", err: "frontendPort: 70000 is out of range"},
+		{src: `70000`, target: NonNull(IntType), key: "count"},
+		{src: `0`, target: NonNull(IntType), key: "port", dangFn: true},
+		{src: `"1m30s"`, target: NonNull(StringType), key: "timeout"},
+		{src: `"30"`, target: NonNull(StringType), key: "pollInterval", err: `pollInterval: invalid duration "30"`},
+		{src: `"30"`, target: NonNull(StringType), key: "name"},
+		{src: `"5 minutes"`, target: NonNull(StringType), key: "timeout", dangFn: true},
+		{src: `"linux/arm64/v8"`, target: NonNull(platform), key: "platform"},
+		{src: `"linux//amd64"`, target: NonNull(platform), key: "platform", err: `platform: invalid platform "linux//amd64"`},
+		{src: `"linux//amd64"`, target: NonNull(StringType), key: "platform"},
+		{src: `"linux//amd64"`, target: NonNull(platform), key: "platform", dangFn: true, err: `platform: invalid platform "linux//amd64"`},
+	} {
+		t.Run(example.key+"="+example.src, func(t *testing.T) {
+			err := checkConstantArgument(parseExpr(t, example.src), example.target, example.key, !example.dangFn)
+			if example.err == "" {
+				require.NoError(t, err)
+			} else {
+				require.ErrorContains(t, err, example.err)
+			}
+		})
+	}
+}
+
+// TestConstantArgumentsOfDangFunctions proves the name-based checks only
+// apply to schema fields: a Dang function decides what its own `port` and
+// `timeout` mean.
+func TestConstantArgumentsOfDangFunctions(t *testing.T) {
+	typeScope := TypeScopeFromSchema("Test", portTestSchema())
+	infer := func(src string) error {
+		parsed, err := ParseWithRecovery("constfold", []byte(src))
+		require.NoError(t, err)
+		_, err = InferFormsWithPhases(context.Background(), parsed.(*FileBlock).Forms, typeScope, hm.NewSimpleFresher())
+		return err
+	}
+
+	require.NoError(t, infer(`listen(port: Int!): Int! { port }
+listen(port: 0)`))
+	require.NoError(t, infer(`wait(timeout: String!): String! { timeout }
+wait(timeout: "5 minutes")`))
+
+	require.ErrorContains(t, infer(`container.withExposedPort(port: 0)`),
+		"port: 0 is out of range; ports are between 1 and 65535")
+	require.ErrorContains(t, infer(`container.withTimeout(timeout: "5 minutes")`),
+		`timeout: invalid duration "5 minutes"`)
+}
+
+// portTestSchema has a root container with a port and a timeout argument.
+func portTestSchema() *introspection.Schema {
+	nonNull := func(kind introspection.TypeKind, name string) *introspection.TypeRef {
+		return &introspection.TypeRef{
+			Kind:   introspection.TypeKindNonNull,
+			OfType: &introspection.TypeRef{Kind: kind, Name: name},
+		}
+	}
+	container := nonNull(introspection.TypeKindObject, "Container")
+	schema := &introspection.Schema{
+		Types: introspection.Types{
+			{Kind: introspection.TypeKindScalar, Name: "ID"},
+			{Kind: introspection.TypeKindScalar, Name: "Int"},
+			{Kind: introspection.TypeKindScalar, Name: "String"},
+			{
+				Kind: introspection.TypeKindObject,
+				Name: "Container",
+				Fields: []*introspection.Field{
+					{Name: "id", TypeRef: nonNull(introspection.TypeKindScalar, "ID")},
+					{
+						Name:    "withExposedPort",
+						Args:    []introspection.InputValue{{Name: "port", TypeRef: nonNull(introspection.TypeKindScalar, "Int")}},
+						TypeRef: container,
+					},
+					{
+						Name:    "withTimeout",
+						Args:    []introspection.InputValue{{Name: "timeout", TypeRef: nonNull(introspection.TypeKindScalar, "String")}},
+						TypeRef: container,
+					},
+				},
+			},
+			{
+				Kind:   introspection.TypeKindObject,
+				Name:   "Query",
+				Fields: []*introspection.Field{{Name: "container", TypeRef: container}},
+			},
+		},
+	}
+	schema.QueryType.Name = "Query"
+	return schema
+}
@@ -0,0 +1,190 @@
+package dang
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/vito/dang/v2/pkg/hm"
+	"github.com/vito/dang/v2/pkg/introspection"
+)
+
+// File and directory helpers: sugar for building files and directories with
+// inline content, which in plain Dagger means threading a scratch directory
+// through withNewFile and pulling the result back out:
+//
+//	file("install.sh", "...")             # directory.withNewFile("install.sh", "...").file("install.sh")
+//	dir(["a.txt": "x", "sub/b.txt": "y"]) # directory.withNewFile("a.txt", "x").withNewFile("sub/b.txt", "y")
+//	dir(files: ["run.sh": script])        # directory.withFile("run.sh", script)
+//
+// They're installed alongside a schema's own bindings whenever the schema
+// has the Dagger shapes they expand to, and never shadow a Query field of the
+// same name.
+
+const (
+	fileHelperName = "file"
+	dirHelperName  = "dir"
+)
+
+// fileHelperTypes returns the File and Directory types the helpers are built
+// on, if schema has everything they expand to.
+func fileHelperTypes(schema *introspection.Schema, types func(string) (TypeScope, bool)) (file, dir TypeScope, ok bool) {
+	if schemaField(schema, schema.QueryType.Name, "directory") == nil {
+		return nil, nil, false
+	}
+	for _, field := range []string{"withNewFile", "withFile", "file"} {
+		if schemaField(schema, "Directory", field) == nil {
+			return nil, nil, false
+		}
+	}
+	file, fileFound := types("File")
+	dir, dirFound := types("Directory")
+	return file, dir, fileFound && dirFound
+}
+
+// fileHelperFnTypes returns the signatures of file and dir. A map holds one
+// value type, so dir takes inline contents and files to copy in as separate
+// maps, each typed so a wrong entry fails to type check.
+func fileHelperFnTypes(file, dir TypeScope) map[string]*hm.FunctionType {
+	fileArgs := NewRecordType("")
+	fileArgs.Add("name", hm.NewScheme(nil, NonNull(StringType)))
+	fileArgs.Add("contents", hm.NewScheme(nil, NonNull(StringType)))
+
+	dirArgs := NewRecordType("")
+	dirArgs.Add("contents", hm.NewScheme(nil, MapType{NonNull(StringType)}))
+	dirArgs.Add("files", hm.NewScheme(nil, MapType{NonNull(file)}))
+
+	return map[string]*hm.FunctionType{
+		fileHelperName: hm.NewFnType(fileArgs, NonNull(file)),
+		dirHelperName:  hm.NewFnType(dirArgs, NonNull(dir)),
+	}
+}
+
+var fileHelperDocs = map[string]string{
+	fileHelperName: "a file with the given name and contents",
+	dirHelperName:  "a directory holding each entry of contents as a new file and each of files copied in at its path, creating parent directories for slash-separated paths",
+}
+
+// installFileHelpers adds the file and dir signatures to a schema's type
+// scope.
+func installFileHelpers(env *OverlayTypeScope, schema *introspection.Schema, schemaTypes map[string]TypeScope) {
+	file, dir, ok := fileHelperTypes(schema, func(name string) (TypeScope, bool) {
+		t, found := schemaTypes[name]
+		return t, found
+	})
+	if !ok {
+		return
+	}
+	for name, fnType := range fileHelperFnTypes(file, dir) {
+		if schemaField(schema, schema.QueryType.Name, name) != nil {
+			continue
+		}
+		env.Add(name, hm.NewScheme(nil, fnType))
+		env.SetVisibility(name, PublicVisibility)
+		env.SetDocString(name, fileHelperDocs[name])
+	}
+}
+
+// populateFileHelpers binds the file and dir implementations into a schema's
+// value scope, mirroring installFileHelpers.
+func populateFileHelpers(env *Object, typeScope TypeScope, schema *introspection.Schema) {
+	file, dir, ok := fileHelperTypes(schema, typeScope.NamedType)
+	if !ok {
+		return
+	}
+	directoryVal, found := env.LookupLocal("directory")
+	if !found {
+		return
+	}
+	directory, ok := directoryVal.(GraphQLFunction)
+	if !ok {
+		return
+	}
+	fnTypes := fileHelperFnTypes(file, dir)
+	impls := map[string]func(context.Context, map[string]Value) (Value, error){
+		fileHelperName: func(ctx context.Context, args map[string]Value) (Value, error) {
+			name := args["name"]
+			built, err := callGraphQLField(ctx, directory, nil)
+			if err != nil {
+				return nil, err
+			}
+			built, err = selectAndCall(ctx, built, "withNewFile", map[string]Value{"path": name, "contents": args["contents"]})
+			if err != nil {
+				return nil, err
+			}
+			return selectAndCall(ctx, built, "file", map[string]Value{"path": name})
+		},
+		dirHelperName: func(ctx context.Context, args map[string]Value) (Value, error) {
+			built, err := callGraphQLField(ctx, directory, nil)
+			if err != nil {
+				return nil, err
+			}
+			// Either map may be omitted; contents go in before files.
+			if contents, ok := args["contents"].(MapValue); ok {
+				for _, path := range contents.Keys {
+					built, err = selectAndCall(ctx, built, "withNewFile", map[string]Value{"path": StringValue{Val: path}, "contents": contents.Entries[path]})
+					if err != nil {
+						return nil, err
+					}
+				}
+			}
+			if files, ok := args["files"].(MapValue); ok {
+				for _, path := range files.Keys {
+					built, err = selectAndCall(ctx, built, "withFile", map[string]Value{"path": StringValue{Val: path}, "source": files.Entries[path]})
+					if err != nil {
+						return nil, err
+					}
+				}
+			}
+			return built, nil
+		},
+	}
+	for name, impl := range impls {
+		if schemaField(schema, schema.QueryType.Name, name) != nil {
+			continue
+		}
+		env.Bind(name, BuiltinFunction{
+			Name:   name,
+			FnType: fnTypes[name],
+			CallFn: func(ctx context.Context, _ ValueScope, args map[string]Value) (Value, error) {
+				return impl(ctx, args)
+			},
+		}, PublicVisibility)
+	}
+}
+
+// selectAndCall calls field on a GraphQL object value, as `recv.field(args)`
+// would.
+func selectAndCall(ctx context.Context, recv Value, field string, args map[string]Value) (Value, error) {
+	gql, ok := recv.(GraphQLValue)
+	if !ok {
+		return nil, fmt.Errorf("cannot select %s from %T", field, recv)
+	}
+	fn, err := gql.SelectField(ctx, field)
+	if err != nil {
+		return nil, err
+	}
+	return callGraphQLField(ctx, fn, args)
+}
+
+func callGraphQLField(ctx context.Context, fn Value, args map[string]Value) (Value, error) {
+	callable, ok := fn.(Callable)
+	if !ok {
+		return nil, fmt.Errorf("%T is not callable", fn)
+	}
+	return callable.Call(ctx, nil, args)
+}
+
+// schemaField returns the named field of a schema type, or nil.
+func schemaField(schema *introspection.Schema, typeName, fieldName string) *introspection.Field {
+	for _, t := range schema.Types {
+		if t.Name != typeName {
+			continue
+		}
+		for _, f := range t.Fields {
+			if f.Name == fieldName {
+				return f
+			}
+		}
+	}
+	return nil
+}
@@ -0,0 +1,149 @@
+package dang
+
+import (
+	"context"
+	"encoding/json"
+	"strings"
+	"testing"
+
+	"github.com/Khan/genqlient/graphql"
+	"github.com/stretchr/testify/require"
+	"github.com/vito/dang/v2/pkg/hm"
+	"github.com/vito/dang/v2/pkg/introspection"
+)
+
+// recordingClient answers every query with data and records the queries.
+type recordingClient struct {
+	data    string
+	queries []string
+}
+
+func (c *recordingClient) MakeRequest(_ context.Context, req *graphql.Request, resp *graphql.Response) error {
+	c.queries = append(c.queries, req.Query)
+	return json.Unmarshal([]byte(c.data), resp.Data)
+}
+
+func TestFileHelper(t *testing.T) {
+	schema := fileHelperTestSchema()
+	client := &recordingClient{data: `{"directory":{"withNewFile":{"file":{"contents":"echo hi"}}}}`}
+	typeScope := TypeScopeFromSchema("Dagger", schema)
+	result := runProgram(t, typeScope, ValueScopeFromSchema(typeScope, client, schema),
+		`file("install.sh", "echo hi").contents`)
+	require.Equal(t, StringValue{Val: "echo hi"}, result)
+
+	require.Len(t, client.queries, 1)
+	query := client.queries[0]
+	require.Contains(t, query, `{directory{withNewFile(`)
+	require.Contains(t, query, `path:"install.sh"`)
+	require.Contains(t, query, `contents:"echo hi"`)
+	require.Contains(t, query, `){file(path:"install.sh"){contents}}}`)
+}
+
+func TestDirHelper(t *testing.T) {
+	schema := fileHelperTestSchema()
+	client := &recordingClient{data: `{"directory":{"withNewFile":{"withNewFile":{"entries":["a.txt","sub/"]}}}}`}
+	typeScope := TypeScopeFromSchema("Dagger", schema)
+	result := runProgram(t, typeScope, ValueScopeFromSchema(typeScope, client, schema),
+		`dir(["a.txt": "x", "sub/b.txt": "y"]).entries.join(",")`)
+	require.Equal(t, StringValue{Val: "a.txt,sub/"}, result)
+
+	require.Len(t, client.queries, 1)
+	query := client.queries[0]
+	first := strings.Index(query, `path:"a.txt"`)
+	second := strings.Index(query, `path:"sub/b.txt"`)
+	require.True(t, first >= 0 && second > first, "expected the entries in order, got %s", query)
+}
+
+func TestDirHelperChecksEntryTypes(t *testing.T) {
+	typeScope := TypeScopeFromSchema("Dagger", fileHelperTestSchema())
+	infer := func(src string) error {
+		parsed, err := Parse("dir", []byte(src))
+		require.NoError(t, err)
+		_, err = InferFormsWithPhases(context.Background(), parsed.(*FileBlock).Forms, typeScope, hm.NewSimpleFresher())
+		return err
+	}
+
+	require.NoError(t, infer(`dir(["a.txt": "x"])`))
+	require.NoError(t, infer(`dir(["a.txt": "x"], files: ["run.sh": file("run.sh", "echo hi")])`))
+	require.Error(t, infer(`dir(["a.txt": 1])`), "contents must be Strings")
+	require.Error(t, infer(`dir(files: ["a.txt": "x"])`), "files must be Files")
+}
+
+func TestFileHelpersNeedDaggerShapes(t *testing.T) {
+	// A schema without Directory gets no helpers.
+	typeScope := TypeScopeFromSchema("Other", secretTestSchema())
+	_, found := typeScope.LocalSchemeOf(fileHelperName)
+	require.False(t, found)
+	_, found = typeScope.LocalSchemeOf(dirHelperName)
+	require.False(t, found)
+}
+
+func fileHelperTestSchema() *introspection.Schema {
+	named := func(kind introspection.TypeKind, name string) *introspection.TypeRef {
+		return &introspection.TypeRef{Kind: kind, Name: name}
+	}
+	nonNull := func(kind introspection.TypeKind, name string) *introspection.TypeRef {
+		return &introspection.TypeRef{Kind: introspection.TypeKindNonNull, OfType: named(kind, name)}
+	}
+	stringArg := func(name string) introspection.InputValue {
+		return introspection.InputValue{Name: name, TypeRef: nonNull(introspection.TypeKindScalar, "String")}
+	}
+	schema := &introspection.Schema{
+		Types: introspection.Types{
+			{Kind: introspection.TypeKindScalar, Name: "ID"},
+			{Kind: introspection.TypeKindScalar, Name: "String"},
+			{
+				Kind: introspection.TypeKindObject,
+				Name: "File",
+				Fields: []*introspection.Field{
+					{Name: "id", TypeRef: nonNull(introspection.TypeKindScalar, "ID")},
+					{Name: "contents", TypeRef: nonNull(introspection.TypeKindScalar, "String")},
+				},
+			},
+			{
+				Kind: introspection.TypeKindObject,
+				Name: "Directory",
+				Fields: []*introspection.Field{
+					{Name: "id", TypeRef: nonNull(introspection.TypeKindScalar, "ID")},
+					{
+						Name: "entries",
+						TypeRef: &introspection.TypeRef{
+							Kind: introspection.TypeKindNonNull,
+							OfType: &introspection.TypeRef{
+								Kind:   introspection.TypeKindList,
+								OfType: nonNull(introspection.TypeKindScalar, "String"),
+							},
+						},
+					},
+					{
+						Name:    "withNewFile",
+						Args:    []introspection.InputValue{stringArg("path"), stringArg("contents")},
+						TypeRef: nonNull(introspection.TypeKindObject, "Directory"),
+					},
+					{
+						Name: "withFile",
+						Args: []introspection.InputValue{
+							stringArg("path"),
+							{Name: "source", TypeRef: nonNull(introspection.TypeKindObject, "File")},
+						},
+						TypeRef: nonNull(introspection.TypeKindObject, "Directory"),
+					},
+					{
+						Name:    "file",
+						Args:    []introspection.InputValue{stringArg("path")},
+						TypeRef: nonNull(introspection.TypeKindObject, "File"),
+					},
+				},
+			},
+			{
+				Kind: introspection.TypeKindObject,
+				Name: "Query",
+				Fields: []*introspection.Field{
+					{Name: "directory", TypeRef: nonNull(introspection.TypeKindObject, "Directory")},
+				},
+			},
+		},
+	}
+	schema.QueryType.Name = "Query"
+	return schema
+}
@@ -0,0 +1,213 @@
+package dang
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+// ModuleDoc is the public API of a module: its types and top-level fields,
+// in declaration order, with their doc strings.
+type ModuleDoc struct {
+	Types  []TypeDoc  `json:"types"`
+	Fields []FieldDoc `json:"fields"`
+}
+
+// TypeDoc documents a public type declaration.
+type TypeDoc struct {
+	Name string `json:"name"`
+	// Kind is the declaring keyword: type, interface, scalar, enum, or union.
+	Kind string `json:"kind"`
+	Doc  string `json:"doc,omitempty"`
+	// Fields are the type's public fields and methods.
+	Fields []FieldDoc `json:"fields,omitempty"`
+	// Values are an enum's values.
+	Values []string `json:"values,omitempty"`
+	// Members are a union's member types.
+	Members []string `json:"members,omitempty"`
+}
+
+// FieldDoc documents a public field or method.
+type FieldDoc struct {
+	Name string `json:"name"`
+	// Signature is the field as it would be declared, without its body, e.g.
+	// `greet(name: String = "world"): String!`.
+	Signature string   `json:"signature"`
+	Doc       string   `json:"doc,omitempty"`
+	Args      []ArgDoc `json:"args,omitempty"`
+}
+
+// ArgDoc documents one argument of a method.
+type ArgDoc struct {
+	Name string `json:"name"`
+	Type string `json:"type"`
+	// Default is the source of the argument's default value, if it has one.
+	Default string `json:"default,omitempty"`
+	Doc     string `json:"doc,omitempty"`
+}
+
+// Doc type-checks the .dang file or directory module at path, as Check does,
+// and documents its public declarations.
+func Doc(ctx context.Context, path string) (*ModuleDoc, error) {
+	blocks, err := checkModule(ctx, path)
+	if err != nil {
+		return nil, err
+	}
+
+	doc := &ModuleDoc{Types: []TypeDoc{}, Fields: []FieldDoc{}}
+	for _, block := range blocks {
+		for _, form := range block.Forms {
+			switch n := form.(type) {
+			case *FieldDecl:
+				if n.Visibility == PublicVisibility {
+					doc.Fields = append(doc.Fields, fieldDoc(n))
+				}
+			case *ObjectDecl:
+				if n.Visibility == PublicVisibility {
+					doc.Types = append(doc.Types, typeDoc(n.Name.Name, "type", n.DocString, n.Value))
+				}
+			case *InterfaceDecl:
+				if n.Visibility == PublicVisibility {
+					doc.Types = append(doc.Types, typeDoc(n.Name.Name, "interface", n.DocString, n.Value))
+				}
+			case *ScalarDecl:
+				if n.Visibility == PublicVisibility {
+					doc.Types = append(doc.Types, typeDoc(n.Name.Name, "scalar", n.DocString, n.Value))
+				}
+			case *EnumDecl:
+				if n.Visibility == PublicVisibility {
+					t := TypeDoc{Name: n.Name.Name, Kind: "enum", Doc: n.DocString}
+					for _, v := range n.Values {
+						t.Values = append(t.Values, v.Name)
+					}
+					doc.Types = append(doc.Types, t)
+				}
+			case *UnionDecl:
+				if n.Visibility == PublicVisibility {
+					t := TypeDoc{Name: n.Name.Name, Kind: "union", Doc: n.DocString}
+					for _, m := range n.Members {
+						t.Members = append(t.Members, m.Name)
+					}
+					doc.Types = append(doc.Types, t)
+				}
+			}
+		}
+	}
+	return doc, nil
+}
+
+func typeDoc(name, kind, docString string, body *Block) TypeDoc {
+	t := TypeDoc{Name: name, Kind: kind, Doc: docString}
+	if body == nil {
+		return t
+	}
+	for _, form := range body.Forms {
+		if field, ok := form.(*FieldDecl); ok && field.Visibility == PublicVisibility {
+			t.Fields = append(t.Fields, fieldDoc(field))
+		}
+	}
+	return t
+}
+
+func fieldDoc(field *FieldDecl) FieldDoc {
+	name := field.Name.Name
+	d := FieldDoc{Name: name, Doc: field.DocString}
+
+	fieldType := field.GetInferredType()
+	fnType, isFn := fieldType.(*hm.FunctionType)
+	if !isFn {
+		d.Signature = fmt.Sprintf("%s: %s", name, formatPublicShapeType(fieldType))
+		// Typed fields with a value are constructor arguments with a default.
+		if field.Type_ != nil && field.Value != nil {
+			d.Signature += " = " + formatDefault(field.Value)
+		}
+		return d
+	}
+
+	var args []string
+	if funDecl, ok := field.Value.(*FunDecl); ok {
+		for _, arg := range funDecl.Args {
+			a := ArgDoc{Name: arg.Name.Name, Doc: arg.DocString}
+			if rec, ok := fnType.Arg().(*RecordType); ok {
+				if scheme, found := rec.SchemeOf(a.Name); found {
+					argType, _ := scheme.Type()
+					a.Type = formatPublicShapeType(argType)
+				}
+			}
+			sig := a.Name + ": " + a.Type
+			if arg.Value != nil {
+				a.Default = formatDefault(arg.Value)
+				sig += " = " + a.Default
+			}
+			d.Args = append(d.Args, a)
+			args = append(args, sig)
+		}
+	}
+	if block := fnType.Block(); block != nil {
+		args = append(args, "&block: "+formatPublicShapeFunctionType(block))
+	}
+
+	ret := formatPublicShapeType(fnType.Ret(false))
+	if len(args) == 0 {
+		d.Signature = fmt.Sprintf("%s: %s", name, ret)
+	} else {
+		d.Signature = fmt.Sprintf("%s(%s): %s", name, strings.Join(args, ", "), ret)
+	}
+	return d
+}
+
+func formatDefault(node Node) string {
+	return strings.TrimSpace(Format(node))
+}
+
+// Markdown renders the documentation as a Markdown document, with each
+// type's fields and methods under its heading.
+func (doc *ModuleDoc) Markdown() string {
+	var b strings.Builder
+	section := func(s string) {
+		if b.Len() > 0 {
+			b.WriteString("\n")
+		}
+		b.WriteString(s)
+		b.WriteString("\n")
+	}
+	docString := func(s string) {
+		if s = strings.TrimSpace(s); s != "" {
+			section(s)
+		}
+	}
+	fields := func(fields []FieldDoc) {
+		for _, f := range fields {
+			section(fmt.Sprintf("### `%s`", f.Signature))
+			docString(f.Doc)
+			var args []string
+			for _, a := range f.Args {
+				if a.Doc != "" {
+					args = append(args, fmt.Sprintf("- `%s`: %s", a.Name, strings.TrimSpace(a.Doc)))
+				}
+			}
+			if len(args) > 0 {
+				section(strings.Join(args, "\n"))
+			}
+		}
+	}
+
+	for _, t := range doc.Types {
+		section(fmt.Sprintf("## %s %s", t.Kind, t.Name))
+		docString(t.Doc)
+		if len(t.Values) > 0 {
+			section("Values: `" + strings.Join(t.Values, "`, `") + "`")
+		}
+		if len(t.Members) > 0 {
+			section("Members: `" + strings.Join(t.Members, "`, `") + "`")
+		}
+		fields(t.Fields)
+	}
+	if len(doc.Fields) > 0 {
+		section("## Fields")
+		fields(doc.Fields)
+	}
+	return b.String()
+}
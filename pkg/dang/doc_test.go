@@ -0,0 +1,88 @@
+package dang
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDocPublicAPI(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "main.dang")
+	require.NoError(t, os.WriteFile(path, []byte(`"""
+a greeter
+"""
+type Greeter {
+  """
+  who to greet
+  """
+  name: String! = "world"
+
+  """
+  greet someone
+  """
+  greet(
+    """
+    the greeting
+    """
+    greeting: String! = "hello"
+  ): String! {
+    greeting + ", " + name
+  }
+
+  let secret = "shh"
+}
+
+enum Color { RED GREEN }
+
+let hidden = 1
+
+pub answer = 42
+`), 0o600))
+
+	doc, err := Doc(context.Background(), path)
+	require.NoError(t, err)
+	require.Equal(t, &ModuleDoc{
+		Types: []TypeDoc{
+			{
+				Name: "Greeter",
+				Kind: "type",
+				Doc:  "a greeter",
+				Fields: []FieldDoc{
+					{
+						Name:      "name",
+						Signature: `name: String! = "world"`,
+						Doc:       "who to greet",
+					},
+					{
+						Name:      "greet",
+						Signature: `greet(greeting: String = "hello"): String!`,
+						Doc:       "greet someone",
+						Args: []ArgDoc{
+							{Name: "greeting", Type: "String", Default: `"hello"`, Doc: "the greeting"},
+						},
+					},
+				},
+			},
+			{Name: "Color", Kind: "enum", Values: []string{"RED", "GREEN"}},
+		},
+		Fields: []FieldDoc{
+			{Name: "answer", Signature: "answer: Int!"},
+		},
+	}, doc)
+
+	require.Equal(t, "## type Greeter\n\n"+
+		"a greeter\n\n"+
+		"### `name: String! = \"world\"`\n\n"+
+		"who to greet\n\n"+
+		"### `greet(greeting: String = \"hello\"): String!`\n\n"+
+		"greet someone\n\n"+
+		"- `greeting`: the greeting\n\n"+
+		"## enum Color\n\n"+
+		"Values: `RED`, `GREEN`\n\n"+
+		"## Fields\n\n"+
+		"### `answer: Int!`\n", doc.Markdown())
+}
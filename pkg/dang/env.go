@@ -189,6 +189,7 @@ type Type struct {
 	Parent TypeScope
 
 	objects          map[string]TypeScope
+	objectOrder      []string
 	vars             map[string]*hm.Scheme
 	varOrder         []string
 	visibility       map[string]Visibility
@@ -365,8 +366,12 @@ func init() {
 	// Install built-in modules (as both objects and values)
 	Prelude.AddObject("Random", RandomModule)
 	Prelude.AddObject("UUID", UUIDModule)
+	Prelude.AddObject("Math", MathModule)
+	Prelude.AddObject("Time", TimeModule)
 	Prelude.Add("Random", hm.NewScheme(nil, hm.NonNullType{Type: RandomModule}))
 	Prelude.Add("UUID", hm.NewScheme(nil, hm.NonNullType{Type: UUIDModule}))
+	Prelude.Add("Math", hm.NewScheme(nil, hm.NonNullType{Type: MathModule}))
+	Prelude.Add("Time", hm.NewScheme(nil, hm.NonNullType{Type: TimeModule}))
 
 	// Install regex types so user code can refer to them by name, and the
 	// Regexp(...) constructor value so a pattern can be built explicitly
@@ -396,11 +401,15 @@ func init() {
 	// GraphQLError additionally exposes the response path and extensions.
 	// extensions is the JSON-encoded extensions object ("{}" when absent) so
 	// it can feed JSON.decode; a builtin-JSON-typed field would be opaque to
-	// both JSON.decode(data: String!) and imported JSON scalars.
+	// both JSON.decode(data: String!) and imported JSON scalars. exitCode
+	// lifts the one extension nearly every handler wants out of Dagger's exec
+	// errors; it is null for everything else.
 	GraphQLErrorType.Add("path", hm.NewScheme(nil, NonNull(ListType{NonNull(StringType)})))
 	GraphQLErrorType.SetVisibility("path", PublicVisibility)
 	GraphQLErrorType.Add("extensions", hm.NewScheme(nil, hm.NonNullType{Type: StringType}))
 	GraphQLErrorType.SetVisibility("extensions", PublicVisibility)
+	GraphQLErrorType.Add("exitCode", hm.NewScheme(nil, IntType))
+	GraphQLErrorType.SetVisibility("exitCode", PublicVisibility)
 
 	// Register standard library builtins
 	registerStdlib()
@@ -608,6 +617,9 @@ func TypeScopeFromSchema(name string, schema *introspection.Schema) TypeScope {
 		}
 	}
 
+	// Build files and directories with inline content: file(...) and dir(...).
+	installFileHelpers(env, schema, schemaTypes)
+
 	for _, t := range schema.Types {
 		install, found := schemaTypes[t.Name]
 		if !found {
@@ -895,17 +907,23 @@ func (e *Type) SetDynamicScopeType(t hm.Type) {
 	e.dynamicScopeType = t
 }
 
+// NamedTypes yields the types declared in this scope in the order they were
+// added, so anything listing them (completions, docs, imports) is stable
+// across runs.
 func (e *Type) NamedTypes() iter.Seq2[string, TypeScope] {
 	return func(yield func(string, TypeScope) bool) {
-		for name, env := range e.objects {
-			if !yield(name, env) {
-				break
+		for _, name := range e.objectOrder {
+			if !yield(name, e.objects[name]) {
+				return
 			}
 		}
 	}
 }
 
 func (e *Type) AddObject(name string, c TypeScope) {
+	if _, exists := e.objects[name]; !exists {
+		e.objectOrder = append(e.objectOrder, name)
+	}
 	e.objects[name] = c
 	e.typeOrigins[name] = LocalBindingOrigin()
 }
@@ -254,6 +254,7 @@ func (g GraphQLFunction) Call(ctx context.Context, scope ValueScope, args map[st
 		// Execute the query and return the scalar value
 		var result any
 		query = query.Bind(&result).Client(g.Client)
+		reportQuery(ctx, g.TypeName+"."+g.Name[strings.LastIndex(g.Name, ".")+1:])
 		if err := query.Execute(ctx); err != nil {
 			return nil, fmt.Errorf("executing GraphQL query for %s.%s: %w", g.TypeName, g.Name, err)
 		}
@@ -308,6 +309,9 @@ func (g GraphQLValue) Type() hm.Type {
 }
 
 func (g GraphQLValue) String() string {
+	if g.TypeName == secretTypeName {
+		return redactedSecret
+	}
 	return fmt.Sprintf("gql:Value:%s.%s", g.TypeName, g.Name)
 }
 
@@ -402,6 +406,7 @@ func ValueScopeFromSchema(typeScope TypeScope, client graphql.Client, schema *in
 
 	// Populate with GraphQL functions from the schema
 	populateSchemaFunctions(env, typeScope, client, schema)
+	populateFileHelpers(env, typeScope, schema)
 
 	// Add builtin functions
 	addBuiltinFunctions(env)
@@ -1021,6 +1026,7 @@ func (m gqlObjectMarshaller) XXX_GraphQLIDType() string {
 func (m gqlObjectMarshaller) XXX_GraphQLID(ctx context.Context) (string, error) {
 	var res string
 	query := m.val.QueryChain.Select("id").Bind(&res).Client(m.val.Client)
+	reportQuery(ctx, m.val.TypeName+".id")
 	if err := query.Execute(ctx); err != nil {
 		return "", err
 	}
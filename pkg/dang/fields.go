@@ -249,6 +249,9 @@ func (s *FieldDecl) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm
 			if _, err := assignableForValue(inferredType, definedType, s.Value); err != nil {
 				return nil, NewInferError(err, s.Value)
 			}
+			if err := checkLiteralScalar(s.Value, definedType, s.Name.Name); err != nil {
+				return nil, NewInferError(err, s.Value)
+			}
 			s.Value = wrapCoerce(s.Value, definedType, s.Name.Name)
 		} else {
 			definedType = inferredType
@@ -20,6 +20,10 @@ type FileBlock struct {
 
 	// Filled in during inference phase
 	TypeScope TypeScope
+
+	// SyntaxErrors are the errors ParseRecovering recovered from by replacing
+	// forms with a *SyntaxErrorNode.
+	SyntaxErrors []error
 }
 
 var _ hm.Expression = (*FileBlock)(nil)
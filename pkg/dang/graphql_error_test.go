@@ -76,3 +76,19 @@ func TestLocateErrorField(t *testing.T) {
 		require.Nil(t, sel.locateErrorField(nil))
 	})
 }
+
+func TestGraphQLErrorExitCode(t *testing.T) {
+	exitCode := func(list gqlerror.List) Value {
+		val, found := newGraphQLError(list).LookupLocal("exitCode")
+		require.True(t, found)
+		return val
+	}
+
+	// Dagger's exec errors carry the exit code, decoded from JSON as a float.
+	require.Equal(t, IntValue{Val: 127}, exitCode(gqlerror.List{{
+		Message:    "process \"false\" did not complete successfully: exit code: 127",
+		Extensions: map[string]any{"_type": "EXEC_ERROR", "exitCode": float64(127)},
+	}}))
+
+	require.Equal(t, NullValue{}, exitCode(gqlerror.List{{Message: "boom"}}))
+}
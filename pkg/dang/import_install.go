@@ -54,7 +54,7 @@ func installUnqualifiedImportValuesForInference(parentTypeScope TypeScope, schem
 
 func installUnqualifiedImportTypesFromModule(parentTypeScope TypeScope, mod *Type, importName string) {
 	origin := ImportedBindingOrigin(importName, false)
-	for name, object := range mod.objects {
+	for name, object := range mod.NamedTypes() {
 		if name == importName {
 			continue
 		}
@@ -147,6 +147,9 @@ func containsDegradableScalar(t hm.Type) bool {
 // object/interface types. Returns nil when no enrichment applies and the
 // caller should use the bare unification error.
 func diagnoseAssignment(have, want hm.Type) error {
+	if wantBase, _ := stripNonNull(want); isSecretType(have) && wantBase == StringType {
+		return errSecretAsString
+	}
 	haveMod, wantMod, issues, ok := walkAssignment(have, want)
 	if !ok {
 		return nil
@@ -432,6 +435,49 @@ func materializeStringValue(ctx context.Context, scope ValueScope, val StringVal
 	}
 }
 
+// checkLiteralScalar runs a Go-native scalar hook over a string literal
+// bound for that scalar, so a literal the hook would reject (an invalid Regexp
+// pattern) is a type error instead of only failing once its call runs. A
+// string literal bound for an enum, e.g. a Dagger enum argument, is likewise
+// checked against the enum's values.
+func checkLiteralScalar(node Node, target hm.Type, path string) error {
+	mod, ok := unwrapNonNull(target).(*Type)
+	if !ok {
+		return nil
+	}
+	hook := mod.GoScalarHook()
+	if mod.Kind != EnumKind && (mod.Kind != ScalarKind || hook == nil) {
+		return nil
+	}
+	var lit string
+	switch n := node.(type) {
+	case *String:
+		lit = n.Value
+	case *Template:
+		if !n.IsLiteralOnly() {
+			return nil
+		}
+		var sb strings.Builder
+		for _, p := range n.Parts {
+			sb.WriteString(p.Lit)
+		}
+		lit = sb.String()
+	default:
+		return nil
+	}
+	if mod.Kind == EnumKind {
+		if !enumHasValue(mod, lit) {
+			return materializeError(path, "invalid enum value %q for %s; valid values: %s",
+				lit, mod.Name(), strings.Join(enumValueNames(mod), ", "))
+		}
+		return nil
+	}
+	if _, err := hook(lit); err != nil {
+		return materializeError(path, "%s", err.Error())
+	}
+	return nil
+}
+
 func materializeDecoded(ctx context.Context, scope ValueScope, raw any, target hm.Type, path string, codec Codec) (Value, error) {
 	if nn, ok := target.(hm.NonNullType); ok {
 		if raw == nil {
@@ -1,15 +1,21 @@
 package dang
 
 import (
+	"bytes"
 	"context"
+	"encoding/json"
 	"errors"
 	"fmt"
+	"strings"
 	"sync"
 	"testing"
 	"testing/synctest"
 
+	"github.com/Khan/genqlient/graphql"
 	"github.com/stretchr/testify/require"
 	"github.com/vito/dang/v2/pkg/hm"
+	"github.com/vito/dang/v2/pkg/introspection"
+	"github.com/vito/dang/v2/pkg/ioctx"
 )
 
 // barrier releases all arrivals only once n of them are waiting at the same
@@ -213,6 +219,13 @@ func barrierModuleScopes(t *testing.T, modName string, n int, hit func()) (TypeS
 // runProgram parses, type-checks, and evaluates src against the given scopes and
 // returns the value of the final form.
 func runProgram(t *testing.T, typeScope TypeScope, valueScope ValueScope, src string) Value {
+	t.Helper()
+	return runProgramContext(context.Background(), t, typeScope, valueScope, src)
+}
+
+// runProgramContext is runProgram with a caller-supplied context, e.g. one
+// carrying stdout.
+func runProgramContext(ctx context.Context, t *testing.T, typeScope TypeScope, valueScope ValueScope, src string) Value {
 	t.Helper()
 	parsed, err := ParseWithRecovery("barrier", []byte(src))
 	require.NoError(t, err)
@@ -220,13 +233,135 @@ func runProgram(t *testing.T, typeScope TypeScope, valueScope ValueScope, src st
 	require.True(t, ok, "unexpected parse result %T", parsed)
 
 	fresh := hm.NewSimpleFresher()
-	_, err = InferFormsWithPhases(context.Background(), file.Forms, typeScope, fresh)
+	_, err = InferFormsWithPhases(ctx, file.Forms, typeScope, fresh)
 	require.NoError(t, err)
 
 	var result Value
 	for _, node := range file.Forms {
-		result, err = EvalNode(context.Background(), valueScope, node)
+		result, err = EvalNode(ctx, valueScope, node)
 		require.NoError(t, err)
 	}
 	return result
 }
+
+// barrierClient answers leaf selections of argsTestSchema's Thing, blocking
+// each request on a barrier first.
+type barrierClient struct {
+	hit func()
+}
+
+func (c barrierClient) MakeRequest(_ context.Context, req *graphql.Request, resp *graphql.Response) error {
+	c.hit()
+	for _, field := range []string{"a", "b", "c", "d"} {
+		if strings.Contains(req.Query, "{"+field+"}") {
+			return json.Unmarshal([]byte(fmt.Sprintf(`{"thing":{%q:"v%s"}}`, field, field)), resp.Data)
+		}
+	}
+	return fmt.Errorf("unexpected query %s", req.Query)
+}
+
+// TestCallArgumentsEvaluateConcurrently proves that a call's arguments are
+// evaluated concurrently when more than one of them resolves a GraphQL
+// selection. Each argument's request blocks on a shared barrier sized to the
+// argument count, so the call only completes if every argument is evaluated
+// at the same time.
+func TestCallArgumentsEvaluateConcurrently(t *testing.T) {
+	const n = 4
+	var active *barrier
+	schema := argsTestSchema()
+	typeScope := TypeScopeFromSchema("Test", schema)
+	valueScope := ValueScopeFromSchema(typeScope, barrierClient{hit: func() { active.arrive() }}, schema)
+
+	synctest.Test(t, func(t *testing.T) {
+		active = newBarrier(n)
+		result := runProgram(t, typeScope, valueScope, `join(a: String!, b: String!, c: String!, d: String!): String! { a + b + c + d }
+join(thing.a, thing.b, c: thing.c, d: thing.d)`)
+		require.Equal(t, StringValue{Val: "vavbvcvd"}, result)
+		require.Equal(t, n, active.peaked, "all arguments should evaluate simultaneously")
+	})
+}
+
+// TestCallArgumentsCallingDangKeepSourceOrder proves that arguments which
+// call a Dang function are evaluated in order, since the function's body may
+// have effects the call site can't see.
+func TestCallArgumentsCallingDangKeepSourceOrder(t *testing.T) {
+	schema := argsTestSchema()
+	typeScope := TypeScopeFromSchema("Test", schema)
+	valueScope := ValueScopeFromSchema(typeScope, barrierClient{hit: func() {}}, schema)
+
+	for range 20 {
+		var out bytes.Buffer
+		ctx := ioctx.StdoutToContext(context.Background(), &out)
+		result := runProgramContext(ctx, t, typeScope, valueScope, `say(s: String!): String! {
+  print(s)
+  s
+}
+join(a: String!, b: String!, c: String!, d: String!): String! { a + b + c + d }
+join(say("1"), say("2"), c: say("3"), d: say("4"))`)
+		require.Equal(t, StringValue{Val: "1234"}, result)
+		require.Equal(t, "1\n2\n3\n4\n", out.String())
+	}
+}
+
+func TestArgsRunConcurrently(t *testing.T) {
+	schema := argsTestSchema()
+	for _, tc := range []struct {
+		src  string
+		want bool
+	}{
+		{`join(thing.a, thing.b)`, true},
+		{`join(thing.child.a, thing.child.child.b)`, true},
+		{`join(saved.a, saved.b, c: saved.c)`, true},
+		{`join(thing.a, "x")`, false},
+		{`join(thing.a, toString(thing.b))`, false},
+		{`join(thing.a, say("b"))`, false},
+		{`join(say("a"), say("b"))`, false},
+		{`join(thing.a, thing.b, greeting)`, false},
+	} {
+		src := `say(s: String!): String! { s }
+greeting: String! { say("hi") }
+let saved = thing
+join(a: String!, b: String!, c: String! = ""): String! { a + b + c }
+` + tc.src
+		parsed, err := ParseWithRecovery("args", []byte(src))
+		require.NoError(t, err)
+		forms := parsed.(*FileBlock).Forms
+		_, err = InferFormsWithPhases(context.Background(), forms, TypeScopeFromSchema("Test", schema), hm.NewSimpleFresher())
+		require.NoError(t, err, tc.src)
+		call, ok := forms[len(forms)-1].(*FunCall)
+		require.True(t, ok, "%s: unexpected form %T", tc.src, forms[len(forms)-1])
+		require.Equal(t, tc.want, argsRunConcurrently(call.Args), tc.src)
+	}
+}
+
+// argsTestSchema has a root `thing` whose Thing has the String leaves a..d
+// and a `child` Thing to chain through.
+func argsTestSchema() *introspection.Schema {
+	nonNull := func(kind introspection.TypeKind, name string) *introspection.TypeRef {
+		return &introspection.TypeRef{
+			Kind:   introspection.TypeKindNonNull,
+			OfType: &introspection.TypeRef{Kind: kind, Name: name},
+		}
+	}
+	thingFields := []*introspection.Field{
+		{Name: "child", TypeRef: nonNull(introspection.TypeKindObject, "Thing")},
+	}
+	for _, name := range []string{"a", "b", "c", "d"} {
+		thingFields = append(thingFields, &introspection.Field{Name: name, TypeRef: nonNull(introspection.TypeKindScalar, "String")})
+	}
+	schema := &introspection.Schema{
+		Types: introspection.Types{
+			{Kind: introspection.TypeKindScalar, Name: "String"},
+			{Kind: introspection.TypeKindObject, Name: "Thing", Fields: thingFields},
+			{
+				Kind: introspection.TypeKindObject,
+				Name: "Query",
+				Fields: []*introspection.Field{
+					{Name: "thing", TypeRef: nonNull(introspection.TypeKindObject, "Thing")},
+				},
+			},
+		},
+	}
+	schema.QueryType.Name = "Query"
+	return schema
+}
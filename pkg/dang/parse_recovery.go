@@ -0,0 +1,260 @@
+package dang
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"regexp"
+	"slices"
+
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+// maxSyntaxRecoveries bounds how many broken forms ParseRecovering will skip
+// before giving up on a file.
+const maxSyntaxRecoveries = 16
+
+// ParseRecovering parses source like ParseWithRecovery, but recovers from
+// syntax errors in top-level forms so editor features keep working on the
+// rest of the file.
+//
+// Each form containing a syntax error is blanked out, leaving every other
+// form at its original position, and replaced in the result by a
+// *SyntaxErrorNode. The errors are recorded in the block's SyntaxErrors. If
+// the file can't be recovered, the first syntax error is returned instead.
+func ParseRecovering(filename string, source []byte, opts ...Option) (*FileBlock, error) {
+	src := bytes.Clone(source)
+	var broken []*SyntaxErrorNode
+	var firstErr error
+	for range maxSyntaxRecoveries + 1 {
+		parsed, err := ParseWithRecovery(filename, src, opts...)
+		if err == nil {
+			block := parsed.(*FileBlock)
+			for _, node := range broken {
+				block.Forms = insertByLine(block.Forms, node)
+				// An error reported past the broken form (e.g. an unclosed
+				// brace at EOF) can take several forms with it; report it once.
+				if !slices.ContainsFunc(block.SyntaxErrors, func(e error) bool { return e.Error() == node.Err.Error() }) {
+					block.SyntaxErrors = append(block.SyntaxErrors, node.Err)
+				}
+			}
+			return block, nil
+		}
+		if firstErr == nil {
+			firstErr = err
+		}
+
+		loc := syntaxErrorLocation(err)
+		if loc == nil {
+			return nil, firstErr
+		}
+		start, end, ok := formLines(src, loc.Line-1)
+		if !ok {
+			return nil, firstErr
+		}
+		name, kind, isType := brokenDeclaration(formHead(src, start))
+		blankLines(src, start, end)
+		broken = append(broken, &SyntaxErrorNode{
+			Err:      err,
+			Declares: name,
+			TypeKind: kind,
+			IsType:   isType,
+			Loc: &SourceLocation{
+				Filename: filename,
+				Line:     start + 1,
+				Column:   1,
+				End:      &SourcePosition{Line: end, Column: 1},
+			},
+		})
+	}
+	return nil, firstErr
+}
+
+// syntaxErrorLocation returns where a parse error occurred.
+func syntaxErrorLocation(err error) *SourceLocation {
+	var sourceErr *SourceError
+	var parseErr interface {
+		ParseErrorLocation() *SourceLocation
+	}
+	switch {
+	case errors.As(err, &sourceErr):
+		return sourceErr.Location
+	case errors.As(err, &parseErr):
+		return parseErr.ParseErrorLocation()
+	default:
+		return nil
+	}
+}
+
+// formLines returns the 0-based range of lines [start, end) making up the
+// top-level form around line. A form starts on an unindented line that isn't
+// a comment or a closing bracket, and runs until the next one.
+func formLines(src []byte, line int) (int, int, bool) {
+	lines := bytes.Split(src, []byte("\n"))
+	if line < 0 || line >= len(lines) {
+		return 0, 0, false
+	}
+	startsForm := func(l []byte) bool {
+		if len(l) == 0 {
+			return false
+		}
+		switch l[0] {
+		case ' ', '\t', '\r', '#', '}', ')', ']':
+			return false
+		}
+		return true
+	}
+
+	start := line
+	for start > 0 && !startsForm(lines[start]) {
+		start--
+	}
+	end := line + 1
+	for end < len(lines) && !startsForm(lines[end]) {
+		end++
+	}
+	if len(bytes.TrimSpace(bytes.Join(lines[start:end], nil))) == 0 {
+		// Already blanked: the error is somewhere we can't isolate.
+		return 0, 0, false
+	}
+	return start, end, true
+}
+
+// formHead returns the 0-based line of src that a form starts on.
+func formHead(src []byte, line int) []byte {
+	lines := bytes.Split(src, []byte("\n"))
+	if line < 0 || line >= len(lines) {
+		return nil
+	}
+	return lines[line]
+}
+
+var (
+	brokenTypeHead  = regexp.MustCompile(`^(?:pub\s+|let\s+)?(type|enum|interface|union|scalar)\s+([A-Za-z_][A-Za-z0-9_]*)`)
+	brokenValueHead = regexp.MustCompile(`^(?:(?:pub|let)\s+([A-Za-z_][A-Za-z0-9_]*)|([A-Za-z_][A-Za-z0-9_]*)\s*:)`)
+)
+
+// brokenDeclaration recovers the name a broken form declares from its head
+// line, e.g. `pub foo = = 1` or `type Foo {`, so that uses of it elsewhere
+// don't cascade into "not found" errors. isType reports a type declaration,
+// of the given kind.
+func brokenDeclaration(head []byte) (name string, kind Kind, isType bool) {
+	if m := brokenTypeHead.FindSubmatch(head); m != nil {
+		kind := ObjectKind
+		switch string(m[1]) {
+		case "enum":
+			kind = EnumKind
+		case "interface":
+			kind = InterfaceKind
+		case "union":
+			kind = UnionKind
+		case "scalar":
+			kind = ScalarKind
+		}
+		return string(m[2]), kind, true
+	}
+	if m := brokenValueHead.FindSubmatch(head); m != nil {
+		name := string(m[1])
+		if name == "" {
+			name = string(m[2])
+		}
+		return name, 0, false
+	}
+	return "", 0, false
+}
+
+// blankLines replaces lines [start, end) of src with spaces, keeping line
+// breaks so that everything after them stays at the same position.
+func blankLines(src []byte, start, end int) {
+	line := 0
+	for i, b := range src {
+		if b == '\n' {
+			line++
+			continue
+		}
+		if line >= start && line < end {
+			src[i] = ' '
+		}
+	}
+}
+
+// insertByLine inserts node among forms in source order.
+func insertByLine(forms []Node, node Node) []Node {
+	line := node.GetSourceLocation().Line
+	for i, form := range forms {
+		if loc := form.GetSourceLocation(); loc != nil && loc.Line > line {
+			return slices.Insert(forms, i, node)
+		}
+	}
+	return append(forms, node)
+}
+
+// SyntaxErrorNode stands in for a top-level form that failed to parse, as
+// recovered by ParseRecovering. It type-checks as an unconstrained value, and
+// declares whatever name the broken form's head still shows (see
+// brokenDeclaration) with an unconstrained type, so the broken form doesn't
+// cascade into further errors.
+type SyntaxErrorNode struct {
+	InferredTypeHolder
+	Err error
+	// Declares is the name the broken form declares, if it could be told.
+	Declares string
+	// IsType reports that Declares names a type, of kind TypeKind.
+	IsType   bool
+	TypeKind Kind
+	Loc      *SourceLocation
+}
+
+var _ Node = (*SyntaxErrorNode)(nil)
+var _ Evaluator = (*SyntaxErrorNode)(nil)
+var _ Hoister = (*SyntaxErrorNode)(nil)
+
+func (n *SyntaxErrorNode) Body() hm.Expression { return n }
+
+func (n *SyntaxErrorNode) GetSourceLocation() *SourceLocation { return n.Loc }
+
+// Hoist registers a broken type declaration's name, so annotations naming it
+// still resolve.
+func (n *SyntaxErrorNode) Hoist(ctx context.Context, env hm.Env, fresh hm.Fresher, pass int) error {
+	if !n.IsType || pass != 0 {
+		return nil
+	}
+	mod, ok := env.(TypeScope)
+	if !ok {
+		return nil
+	}
+	if _, err := declareLocalType(ctx, mod, n.Declares, n.TypeKind); err != nil {
+		return NewInferError(err, n)
+	}
+	assignFallbackType(n, env, fresh)
+	return nil
+}
+
+func (n *SyntaxErrorNode) Infer(ctx context.Context, env hm.Env, fresh hm.Fresher) (hm.Type, error) {
+	if n.Declares != "" && !n.IsType {
+		assignFallbackType(n, env, fresh)
+	}
+	t := fresh.Fresh()
+	n.SetInferredType(t)
+	return t, nil
+}
+
+func (n *SyntaxErrorNode) DeclaredSymbols() []string {
+	if n.Declares == "" {
+		return nil
+	}
+	return []string{n.Declares}
+}
+
+func (n *SyntaxErrorNode) ReferencedSymbols() []string {
+	return nil
+}
+
+func (n *SyntaxErrorNode) Eval(ctx context.Context, _ ValueScope) (Value, error) {
+	return nil, fmt.Errorf("cannot evaluate a form with a syntax error: %w", n.Err)
+}
+
+func (n *SyntaxErrorNode) Walk(fn func(Node) bool) {
+	fn(n)
+}
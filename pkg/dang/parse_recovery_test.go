@@ -0,0 +1,79 @@
+package dang
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestParseRecoveringSkipsBrokenForms(t *testing.T) {
+	src := `pub before = 1
+
+type Broken {
+  pub name: String! = = "x"
+}
+
+pub after = before + 1
+
+pub broken = = 2
+
+pub again = broken
+
+pub describe(b: Broken!): String! { "broken" }
+`
+	block, err := ParseRecovering("main.dang", []byte(src))
+	require.NoError(t, err)
+	require.Len(t, block.SyntaxErrors, 2)
+	require.Equal(t, 4, syntaxErrorLocation(block.SyntaxErrors[0]).Line)
+	require.Equal(t, 9, syntaxErrorLocation(block.SyntaxErrors[1]).Line)
+
+	require.Len(t, block.Forms, 6)
+	require.IsType(t, &FieldDecl{}, block.Forms[0])
+	brokenType := block.Forms[1].(*SyntaxErrorNode)
+	require.Equal(t, 3, brokenType.Loc.Line)
+	require.Equal(t, []string{"Broken"}, brokenType.DeclaredSymbols())
+	require.True(t, brokenType.IsType)
+	after := block.Forms[2].(*FieldDecl)
+	require.Equal(t, "after", after.Name.Name)
+	// Forms after the broken one keep their positions.
+	require.Equal(t, 7, after.Loc.Line)
+	brokenValue := block.Forms[3].(*SyntaxErrorNode)
+	require.Equal(t, []string{"broken"}, brokenValue.DeclaredSymbols())
+	require.False(t, brokenValue.IsType)
+
+	// The broken forms don't cascade into type errors, even where the names
+	// they declare are used.
+	_, err = Infer(context.Background(), NewPreludeTypeScope(""), block, true)
+	require.NoError(t, err)
+}
+
+func TestBrokenDeclaration(t *testing.T) {
+	for _, example := range []struct {
+		head   string
+		name   string
+		kind   Kind
+		isType bool
+	}{
+		{head: "pub foo = = 1", name: "foo"},
+		{head: "let foo(x: Int!): Int! {", name: "foo"},
+		{head: "foo: Int! = = 1", name: "foo"},
+		{head: "type Foo {", name: "Foo", isType: true},
+		{head: "pub enum Color {", name: "Color", kind: EnumKind, isType: true},
+		{head: "interface Named {", name: "Named", kind: InterfaceKind, isType: true},
+		{head: "print(1 +)"},
+		{head: "assert { x == }"},
+	} {
+		name, kind, isType := brokenDeclaration([]byte(example.head))
+		require.Equal(t, example.name, name, example.head)
+		require.Equal(t, example.kind, kind, example.head)
+		require.Equal(t, example.isType, isType, example.head)
+	}
+}
+
+func TestParseRecoveringValidSource(t *testing.T) {
+	block, err := ParseRecovering("main.dang", []byte("pub x = 1\n"))
+	require.NoError(t, err)
+	require.Empty(t, block.SyntaxErrors)
+	require.Len(t, block.Forms, 1)
+}
@@ -0,0 +1,97 @@
+package dang
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"sync"
+)
+
+// ProgressKind classifies a ProgressEvent.
+type ProgressKind string
+
+const (
+	// ProgressCall is reported when evaluation enters a Dang function or
+	// method.
+	ProgressCall ProgressKind = "call"
+	// ProgressQuery is reported when evaluation sends a GraphQL query, e.g. to
+	// resolve a Dagger operation.
+	ProgressQuery ProgressKind = "query"
+)
+
+// ProgressEvent describes a step of a long-running evaluation.
+type ProgressEvent struct {
+	Kind ProgressKind
+	// Name is the function being entered, or the field the query resolves,
+	// e.g. Container.stdout.
+	Name string
+	// Location is the span of the expression being evaluated, if known.
+	Location *SourceLocation
+}
+
+// ProgressSink receives progress events during evaluation. It may be called
+// concurrently, e.g. from parallel selections.
+type ProgressSink func(ProgressEvent)
+
+type progressSinkKey struct{}
+type progressLocationKey struct{}
+
+// WithProgress reports progress events for evaluations run with the returned
+// context to sink. Without one (the default), no events are built.
+func WithProgress(ctx context.Context, sink ProgressSink) context.Context {
+	return context.WithValue(ctx, progressSinkKey{}, sink)
+}
+
+func progressFromContext(ctx context.Context) ProgressSink {
+	sink, _ := ctx.Value(progressSinkKey{}).(ProgressSink)
+	return sink
+}
+
+// ProgressPrinter returns a sink that prints one line per event to w, e.g.
+// `query Container.stdout (main.dang:12:3)`. Writes are serialized, so w
+// needn't be safe for concurrent use.
+func ProgressPrinter(w io.Writer) ProgressSink {
+	var mu sync.Mutex
+	return func(ev ProgressEvent) {
+		mu.Lock()
+		defer mu.Unlock()
+		if loc := ev.Location; loc != nil && loc.Filename != "" {
+			_, _ = fmt.Fprintf(w, "%s %s (%s:%d:%d)\n", ev.Kind, ev.Name, loc.Filename, loc.Line, loc.Column)
+			return
+		}
+		_, _ = fmt.Fprintf(w, "%s %s\n", ev.Kind, ev.Name)
+	}
+}
+
+// withProgressLocation records loc as the span being evaluated, so that
+// queries sent while evaluating it are reported against it.
+func withProgressLocation(ctx context.Context, loc *SourceLocation) context.Context {
+	if loc == nil || progressFromContext(ctx) == nil {
+		return ctx
+	}
+	return context.WithValue(ctx, progressLocationKey{}, loc)
+}
+
+// reportCall reports entering fn, named name, at loc. Builtins aren't
+// reported; they return promptly, and would drown out the calls that matter.
+func reportCall(ctx context.Context, name string, fn Value, loc *SourceLocation) {
+	sink := progressFromContext(ctx)
+	if sink == nil {
+		return
+	}
+	switch fn.(type) {
+	case FunctionValue, BoundMethod, BoundScalarMethod:
+		sink(ProgressEvent{Kind: ProgressCall, Name: name, Location: loc})
+	}
+}
+
+// reportQuery reports sending a query that resolves name, against the span
+// being evaluated.
+func reportQuery(ctx context.Context, name string) {
+	sink := progressFromContext(ctx)
+	if sink == nil {
+		return
+	}
+	loc, _ := ctx.Value(progressLocationKey{}).(*SourceLocation)
+	sink(ProgressEvent{Kind: ProgressQuery, Name: name, Location: loc})
+}
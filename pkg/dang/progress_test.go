@@ -0,0 +1,73 @@
+package dang
+
+import (
+	"bytes"
+	"context"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+	"testing"
+)
+
+// TestProgressReportsDangCalls checks that entering a Dang method reports a
+// progress event at the call site, while builtin calls stay quiet.
+func TestProgressReportsDangCalls(t *testing.T) {
+	dir := t.TempDir()
+	file := filepath.Join(dir, "main.dang")
+	src := `type Greeter {
+  greet(name: String!): String! {
+    "hi " + name
+  }
+}
+
+let g = Greeter
+pub out = g.greet("you").toUpper
+`
+	if err := os.WriteFile(file, []byte(src), 0o644); err != nil {
+		t.Fatal(err)
+	}
+
+	var progress bytes.Buffer
+	ctx := WithProgress(context.Background(), ProgressPrinter(&progress))
+	if err := RunFile(ctx, file, false); err != nil {
+		t.Fatalf("RunFile: %v", err)
+	}
+
+	lines := strings.Split(strings.TrimSpace(progress.String()), "\n")
+	if len(lines) != 1 {
+		t.Fatalf("expected one progress event, got %q", progress.String())
+	}
+	if !strings.HasPrefix(lines[0], "call greet ("+file+":8:") {
+		t.Errorf("expected a call event for greet at line 8, got %q", lines[0])
+	}
+}
+
+// TestProgressPrinterIsConcurrencySafe reports from many goroutines at once
+// into a plain buffer; run with -race to catch unsynchronized writes.
+func TestProgressPrinterIsConcurrencySafe(t *testing.T) {
+	var out bytes.Buffer
+	sink := ProgressPrinter(&out)
+	var wg sync.WaitGroup
+	for range 16 {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			sink(ProgressEvent{Kind: ProgressQuery, Name: "Container.stdout"})
+		}()
+	}
+	wg.Wait()
+	if got := strings.Count(out.String(), "query Container.stdout\n"); got != 16 {
+		t.Errorf("expected 16 intact lines, got %d in %q", got, out.String())
+	}
+}
+
+// TestProgressWithoutSinkIsSilent checks that without a sink the location
+// isn't tracked.
+func TestProgressWithoutSinkIsSilent(t *testing.T) {
+	ctx := context.Background()
+	loc := &SourceLocation{Filename: "main.dang", Line: 1, Column: 1}
+	if withProgressLocation(ctx, loc) != ctx {
+		t.Error("expected the context to be unchanged without a progress sink")
+	}
+}
@@ -7,10 +7,12 @@ import (
 	"fmt"
 	"io"
 	"log/slog"
+	"maps"
 	"net/http"
 	"os"
 	"os/exec"
 	"path/filepath"
+	"slices"
 	"strings"
 	"sync"
 	"time"
@@ -702,8 +704,10 @@ func astSchemaToIntrospection(schema *ast.Schema) *introspection.Schema {
 		}{Name: schema.Subscription.Name}
 	}
 
-	// Convert types
-	for _, t := range schema.Types {
+	// Convert types. The parsed schema keys them by name in a map, so sort
+	// them for a stable order across runs.
+	for _, name := range slices.Sorted(maps.Keys(schema.Types)) {
+		t := schema.Types[name]
 		// Skip built-in introspection types
 		if strings.HasPrefix(t.Name, "__") {
 			continue
@@ -712,7 +716,8 @@ func astSchemaToIntrospection(schema *ast.Schema) *introspection.Schema {
 	}
 
 	// Convert directives
-	for _, d := range schema.Directives {
+	for _, name := range slices.Sorted(maps.Keys(schema.Directives)) {
+		d := schema.Directives[name]
 		// Skip built-in directives
 		if d.Name == "skip" || d.Name == "include" || d.Name == "deprecated" || d.Name == "specifiedBy" {
 			continue
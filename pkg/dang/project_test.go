@@ -5,6 +5,7 @@ import (
 	"fmt"
 	"os"
 	"path/filepath"
+	"slices"
 	"testing"
 
 	"github.com/stretchr/testify/assert"
@@ -143,6 +144,27 @@ func TestSchemaFromSDLFile(t *testing.T) {
 	require.NotNil(t, schema.Types.Get("ServerInfo"))
 }
 
+func TestSchemaFromSDLTypeOrderIsStable(t *testing.T) {
+	schema, err := SchemaFromSDLFile("../../tests/gqlserver/schema.graphqls")
+	require.NoError(t, err)
+
+	var names []string
+	for _, typ := range schema.Types {
+		names = append(names, typ.Name)
+	}
+	require.True(t, slices.IsSorted(names), "types out of order: %v", names)
+
+	for range 5 {
+		again, err := SchemaFromSDLFile("../../tests/gqlserver/schema.graphqls")
+		require.NoError(t, err)
+		var againNames []string
+		for _, typ := range again.Types {
+			againNames = append(againNames, typ.Name)
+		}
+		require.Equal(t, names, againNames)
+	}
+}
+
 func TestProjectConfigImports(t *testing.T) {
 	// Test that dang.json schema-only imports work for type checking.
 	// This simulates what the LSP does: type-check using only the SDL schema.
@@ -0,0 +1,27 @@
+package dang
+
+import (
+	"errors"
+
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+// secretTypeName is the name of Dagger's Secret type. A Secret's plaintext
+// must never end up in a string by accident — a token interpolated into a
+// command line is baked into an image layer for good — so the type checker
+// refuses to treat one as a string, and the evaluator redacts it wherever a
+// value is rendered.
+const secretTypeName = "Secret"
+
+// redactedSecret is how a Secret value renders.
+const redactedSecret = "[redacted]"
+
+var errSecretAsString = errors.New("cannot use a Secret as a string: pass it to withSecretVariable (or another Secret-typed argument) instead, or call plaintext explicitly if it really must be exposed")
+
+// isSecretType reports whether t is Dagger's Secret, as imported from a
+// schema. A user type that happens to share the name doesn't count.
+func isSecretType(t hm.Type) bool {
+	t, _ = stripNonNull(t)
+	mod, ok := t.(*Type)
+	return ok && mod.Named == secretTypeName && mod.SourceSchema != nil
+}
@@ -0,0 +1,70 @@
+package dang
+
+import (
+	"context"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+	"github.com/vito/dang/v2/pkg/hm"
+	"github.com/vito/dang/v2/pkg/introspection"
+)
+
+func TestSecretsDoNotCoerceToStrings(t *testing.T) {
+	for _, src := range []string{
+		"let s = secret\n`TOKEN=${s}`",
+		"let s = secret\n\"TOKEN=\" + s",
+		"let s = secret\necho(s)",
+		"let s: String! = secret",
+	} {
+		parsed, err := Parse("test.dang", []byte(src))
+		require.NoError(t, err)
+		env := TypeScopeFromSchema("Dagger", secretTestSchema())
+		_, err = parsed.(*FileBlock).Infer(context.Background(), env, hm.NewSimpleFresher())
+		require.ErrorContains(t, err, "cannot use a Secret as a string", src)
+	}
+}
+
+func TestSecretsRenderRedacted(t *testing.T) {
+	secret := GraphQLValue{Name: "secret", TypeName: "Secret"}
+	require.Equal(t, "[redacted]", secret.String())
+	require.Equal(t, "[[redacted]]", Repr(ListValue{Elements: []Value{secret}}))
+}
+
+func secretTestSchema() *introspection.Schema {
+	nonNull := func(kind introspection.TypeKind, name string) *introspection.TypeRef {
+		return &introspection.TypeRef{
+			Kind:   introspection.TypeKindNonNull,
+			OfType: &introspection.TypeRef{Kind: kind, Name: name},
+		}
+	}
+	schema := &introspection.Schema{
+		Types: introspection.Types{
+			{Kind: introspection.TypeKindScalar, Name: "ID"},
+			{Kind: introspection.TypeKindScalar, Name: "String"},
+			{
+				Kind: introspection.TypeKindObject,
+				Name: "Secret",
+				Fields: []*introspection.Field{
+					{Name: "id", TypeRef: nonNull(introspection.TypeKindScalar, "ID")},
+					{Name: "plaintext", TypeRef: nonNull(introspection.TypeKindScalar, "String")},
+				},
+			},
+			{
+				Kind: introspection.TypeKindObject,
+				Name: "Query",
+				Fields: []*introspection.Field{
+					{Name: "secret", TypeRef: nonNull(introspection.TypeKindObject, "Secret")},
+					{
+						Name: "echo",
+						Args: []introspection.InputValue{
+							{Name: "value", TypeRef: nonNull(introspection.TypeKindScalar, "String")},
+						},
+						TypeRef: nonNull(introspection.TypeKindScalar, "String"),
+					},
+				},
+			},
+		},
+	}
+	schema.QueryType.Name = "Query"
+	return schema
+}
@@ -0,0 +1,175 @@
+package dang
+
+import (
+	"context"
+	"strings"
+
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+// Signature describes the function whose arguments are being written at the
+// cursor, for signature help.
+type Signature struct {
+	// Label is the whole signature, e.g.
+	// `from(address: String!, platform?: Platform): Container!`. Optional
+	// parameters are marked with a `?` after their name.
+	Label         string
+	Documentation string
+	Params        []SignatureParam
+	// ActiveParam is the index in Params of the argument under the cursor. It
+	// is len(Params) when that argument matches none of them.
+	ActiveParam int
+}
+
+// SignatureParam is one parameter of a Signature.
+type SignatureParam struct {
+	Name          string
+	Documentation string
+	// Start and End are the byte offsets of the parameter within the
+	// signature's Label.
+	Start, End int
+}
+
+// SignatureAt returns the signature of the innermost call whose argument list
+// contains the cursor, or nil if the cursor isn't in one or its function
+// can't be resolved in env.
+//
+// Like Complete, it finds the call with tree-sitter, so it also works on
+// calls that don't parse yet. The active parameter is the one named by the
+// argument being written (`expand: |`), or else the one at its position,
+// counting commas.
+func SignatureAt(ctx context.Context, env TypeScope, text string, line, col int) *Signature {
+	call := openCallAt(text, line, col)
+	if call == nil {
+		return nil
+	}
+
+	fnType, doc := resolveCallee(ctx, env, call.receiver, call.name)
+	if fnType == nil {
+		return nil
+	}
+	sig := signatureOf(call.name, fnType)
+	if sig == nil {
+		return nil
+	}
+	sig.Documentation = doc
+
+	sig.ActiveParam = min(call.argIndex, len(sig.Params))
+	if call.argName != "" {
+		sig.ActiveParam = len(sig.Params)
+		for i, p := range sig.Params {
+			if p.Name == call.argName {
+				sig.ActiveParam = i
+			}
+		}
+	}
+	return sig
+}
+
+// openCall is a call whose argument list is still open at the cursor.
+type openCall struct {
+	receiver string // receiver expression, or "" for a bare function call
+	name     string // function or method name
+	argIndex int    // position of the argument being written
+	argName  string // name of the argument being written, if it's named
+}
+
+// namedArg returns the name of a `name: value` argument, or "" if arg is
+// positional.
+func namedArg(arg string) string {
+	arg = strings.TrimLeft(arg, " \t\r\n")
+	i := 0
+	for i < len(arg) && isIdentByte(arg[i]) {
+		i++
+	}
+	if i == 0 || !strings.HasPrefix(strings.TrimLeft(arg[i:], " \t"), ":") {
+		return ""
+	}
+	return arg[:i]
+}
+
+// resolveCallee returns the type of the function being called along with its
+// doc string. A method is looked up on its receiver's type rather than
+// selected, so that one with no required arguments resolves to its function
+// type instead of being auto-called.
+func resolveCallee(ctx context.Context, env TypeScope, receiver, name string) (hm.Type, string) {
+	scope := env
+	if receiver != "" {
+		t := InferReceiverType(ctx, env, receiver)
+		if nn, ok := t.(hm.NonNullType); ok {
+			t = nn.Type
+		}
+		module, ok := t.(TypeScope)
+		if !ok {
+			if mod := builtinModuleFor(t); mod != nil {
+				module = mod
+			} else {
+				return nil, ""
+			}
+		}
+		scope = module
+	}
+
+	scheme, found := scope.SchemeOf(name)
+	if !found {
+		return nil, ""
+	}
+	t, _ := scheme.Type()
+	doc, _ := scope.GetDocString(name)
+	return t, doc
+}
+
+// signatureOf renders the signature of calling the function t by name, or
+// returns nil if t isn't a function taking named arguments.
+func signatureOf(name string, t hm.Type) *Signature {
+	if nn, ok := t.(hm.NonNullType); ok {
+		t = nn.Type
+	}
+	ft, ok := t.(*hm.FunctionType)
+	if !ok {
+		return nil
+	}
+	argType := ft.Arg()
+	if nn, ok := argType.(hm.NonNullType); ok {
+		argType = nn.Type
+	}
+	record, ok := argType.(*RecordType)
+	if !ok {
+		return nil
+	}
+
+	sig := &Signature{}
+	var label strings.Builder
+	label.WriteString(name + "(")
+	for i, field := range record.Fields {
+		if i > 0 {
+			label.WriteString(", ")
+		}
+		fieldType, _ := field.Value.Type()
+		param := SignatureParam{
+			Name:  field.Key,
+			Start: label.Len(),
+		}
+		label.WriteString(field.Key)
+		// Arguments with defaults are nullable in the signature, so only
+		// non-null ones are required.
+		if _, required := fieldType.(hm.NonNullType); !required {
+			label.WriteString("?")
+		}
+		label.WriteString(": " + fieldType.String())
+		param.End = label.Len()
+		if record.DocStrings != nil {
+			param.Documentation = record.DocStrings[field.Key]
+		}
+		sig.Params = append(sig.Params, param)
+	}
+	if block := ft.Block(); block != nil {
+		if len(record.Fields) > 0 {
+			label.WriteString(", ")
+		}
+		label.WriteString("&block: " + block.String())
+	}
+	label.WriteString("): " + ft.Ret(false).String())
+	sig.Label = label.String()
+	return sig
+}
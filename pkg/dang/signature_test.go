@@ -0,0 +1,180 @@
+//go:build cgo
+
+package dang
+
+import (
+	"context"
+	"testing"
+
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+func testSignatureTypeScope(t *testing.T) TypeScope {
+	t.Helper()
+
+	env := NewPreludeTypeScope("")
+
+	// A schema-style type, as loaded from GraphQL introspection.
+	containerMod := NewType("Container", ObjectKind)
+	execArgs := NewRecordType("")
+	execArgs.Add("args", hm.NewScheme(nil, hm.NonNullType{Type: ListType{Type: hm.NonNullType{Type: StringType}}}))
+	execArgs.Add("expand", hm.NewScheme(nil, BooleanType))
+	execArgs.DocStrings = map[string]string{
+		"args":   "Command to execute.",
+		"expand": "Replace ${VAR} in args.",
+	}
+	containerMod.Add("withExec", hm.NewScheme(nil, hm.NewFnType(execArgs, hm.NonNullType{Type: containerMod})))
+	containerMod.SetVisibility("withExec", PublicVisibility)
+	containerMod.SetDocString("withExec", "Execute a command in the container.")
+	env.Add("container", hm.NewScheme(nil, hm.NonNullType{Type: containerMod}))
+	env.SetVisibility("container", PublicVisibility)
+	env.AddObject("Container", containerMod)
+
+	parsed, err := Parse("signature.dang", []byte(`
+type Greeter {
+  """
+  Greets someone by name.
+  """
+  greet(name: String!, loud: Boolean! = false): String! { name }
+}
+
+let greeter = Greeter
+`))
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+	if _, err := parsed.(*FileBlock).Infer(context.Background(), env, hm.NewSimpleFresher()); err != nil {
+		t.Fatalf("infer: %v", err)
+	}
+	return env
+}
+
+func TestSignatureAt(t *testing.T) {
+	env := testSignatureTypeScope(t)
+	ctx := context.Background()
+
+	tests := []struct {
+		name       string
+		text       string
+		line, col  int
+		wantLabel  string
+		wantDoc    string
+		wantActive int
+	}{
+		{
+			name:       "open paren",
+			text:       "container.withExec(",
+			col:        19,
+			wantLabel:  "withExec(args: [String!]!, expand?: Boolean): Container!",
+			wantDoc:    "Execute a command in the container.",
+			wantActive: 0,
+		},
+		{
+			name:       "after comma",
+			text:       `container.withExec(["sh", "-c"], `,
+			col:        33,
+			wantLabel:  "withExec(args: [String!]!, expand?: Boolean): Container!",
+			wantDoc:    "Execute a command in the container.",
+			wantActive: 1,
+		},
+		{
+			name:       "inside list literal",
+			text:       `container.withExec(["sh", `,
+			col:        26,
+			wantLabel:  "withExec(args: [String!]!, expand?: Boolean): Container!",
+			wantDoc:    "Execute a command in the container.",
+			wantActive: 0,
+		},
+		{
+			name:       "named argument",
+			text:       "container.withExec(expand: ",
+			col:        27,
+			wantLabel:  "withExec(args: [String!]!, expand?: Boolean): Container!",
+			wantDoc:    "Execute a command in the container.",
+			wantActive: 1,
+		},
+		{
+			name:       "multi-line chain",
+			text:       "container\n  .withExec([\"a\"])\n  .withExec(",
+			line:       2,
+			col:        12,
+			wantLabel:  "withExec(args: [String!]!, expand?: Boolean): Container!",
+			wantDoc:    "Execute a command in the container.",
+			wantActive: 0,
+		},
+		{
+			name:       "user-defined method",
+			text:       `greeter.greet("bob", `,
+			col:        21,
+			wantLabel:  "greet(name: String!, loud?: Boolean): String!",
+			wantDoc:    "Greets someone by name.",
+			wantActive: 1,
+		},
+		{
+			name:       "after a nested call",
+			text:       `greeter.greet(greeter.greet("a"), `,
+			col:        34,
+			wantLabel:  "greet(name: String!, loud?: Boolean): String!",
+			wantDoc:    "Greets someone by name.",
+			wantActive: 1,
+		},
+		{
+			name:       "just past a nested call",
+			text:       `greeter.greet(greeter.greet("a")`,
+			col:        32,
+			wantLabel:  "greet(name: String!, loud?: Boolean): String!",
+			wantDoc:    "Greets someone by name.",
+			wantActive: 0,
+		},
+		{
+			name:       "too many arguments",
+			text:       `greeter.greet("bob", true, `,
+			col:        27,
+			wantLabel:  "greet(name: String!, loud?: Boolean): String!",
+			wantDoc:    "Greets someone by name.",
+			wantActive: 2,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			sig := SignatureAt(ctx, env, tt.text, tt.line, tt.col)
+			if sig == nil {
+				t.Fatal("expected a signature")
+			}
+			if sig.Label != tt.wantLabel {
+				t.Errorf("label = %q, want %q", sig.Label, tt.wantLabel)
+			}
+			if sig.Documentation != tt.wantDoc {
+				t.Errorf("documentation = %q, want %q", sig.Documentation, tt.wantDoc)
+			}
+			if sig.ActiveParam != tt.wantActive {
+				t.Errorf("active param = %d, want %d", sig.ActiveParam, tt.wantActive)
+			}
+			for _, p := range sig.Params {
+				if got := sig.Label[p.Start:p.End]; got[:len(p.Name)] != p.Name {
+					t.Errorf("param %q spans %q", p.Name, got)
+				}
+			}
+		})
+	}
+}
+
+func TestSignatureAt_None(t *testing.T) {
+	env := testSignatureTypeScope(t)
+	ctx := context.Background()
+
+	for _, text := range []string{
+		"container.withExec",
+		`container.withExec(["sh"])`,
+		`container.withExec(["sh (`,
+		"container.withExec(  # (",
+		"(1 + ",
+		"[1, 2].map { x => x + ",
+		"missing(",
+	} {
+		if sig := SignatureAt(ctx, env, text, 0, len(text)); sig != nil {
+			t.Errorf("%q: expected no signature, got %q", text, sig.Label)
+		}
+	}
+}
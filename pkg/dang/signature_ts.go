@@ -0,0 +1,169 @@
+//go:build cgo
+
+package dang
+
+import (
+	"strings"
+
+	tree_sitter "github.com/tree-sitter/go-tree-sitter"
+)
+
+// openCallAt finds the innermost call left open at the cursor, using the
+// same tree-sitter lookup as argument completion. It returns nil when the
+// cursor is inside a string, a comment, or a block nested within the call,
+// since none of those are argument positions.
+func openCallAt(text string, line, col int) *openCall {
+	source := []byte(text)
+
+	// tree-sitter parsers are not thread-safe; serialize access.
+	tsMu.Lock()
+	tree := tsParser.Parse(source, nil)
+	tsMu.Unlock()
+
+	if tree == nil {
+		return nil
+	}
+	defer tree.Close()
+
+	root := tree.RootNode()
+	l, c := uint(line), uint(col)
+	if cursorInStringContent(root, l, c) || cursorInComment(root, l, c) {
+		return nil
+	}
+
+	var funcText string
+	var args []*tree_sitter.Node
+	argValues := argValuesAtCursor(root, l, c)
+	for argValues != nil && closedBefore(argValues, l, c) {
+		// The cursor is just past a call that's already closed, which may
+		// itself be an argument of an enclosing call.
+		argValues = enclosingArgValues(argValues)
+	}
+	if argValues != nil {
+		if cursorInBlock(argValues, l, c) {
+			return nil
+		}
+		funcText = extractFuncText(argValues.Parent(), source)
+		args = childrenFrom(argValues, 0)
+	} else if errorNode, parenIdx := openParenErrorAtCursor(root, l, c); errorNode != nil {
+		funcText = funcTextFromError(errorNode, parenIdx, source)
+		args = childrenFrom(errorNode, parenIdx)
+	}
+	if funcText == "" {
+		return nil
+	}
+
+	call := &openCall{name: funcText}
+	if dot := strings.LastIndexByte(funcText, '.'); dot >= 0 {
+		call.receiver, call.name = funcText[:dot], funcText[dot+1:]
+	}
+	for i := 0; i < len(call.name); i++ {
+		if !isIdentByte(call.name[i]) {
+			return nil
+		}
+	}
+
+	// args starts at the call's paren. Count the commas between it and the
+	// cursor that separate its own arguments, not those of a list or
+	// another call nested in an argument that hasn't parsed as one node.
+	offset := lineColToOffset(source, l, c)
+	argStart := 0
+	depth, braces := 0, 0
+	for _, arg := range args {
+		if int(arg.StartByte()) >= offset {
+			break
+		}
+		switch arg.Kind() {
+		case "immediate_paren", "(", "[", "immediate_bracket":
+			depth++
+			if depth == 1 {
+				argStart = int(arg.EndByte())
+			}
+		case ")", "]":
+			depth--
+		case "{":
+			braces++
+		case "}":
+			braces--
+		case "sep":
+			if depth == 1 {
+				call.argIndex++
+				argStart = int(arg.EndByte())
+			}
+		}
+	}
+	if braces > 0 || argStart > offset {
+		return nil
+	}
+	call.argName = namedArg(text[argStart:offset])
+	return call
+}
+
+// childrenFrom returns the children of node from index start on.
+func childrenFrom(node *tree_sitter.Node, start int) []*tree_sitter.Node {
+	var children []*tree_sitter.Node
+	for i := uint(start); i < node.ChildCount(); i++ {
+		if child := node.Child(i); child != nil {
+			children = append(children, child)
+		}
+	}
+	return children
+}
+
+// enclosingArgValues returns the nearest arg_values node around node, or nil.
+func enclosingArgValues(node *tree_sitter.Node) *tree_sitter.Node {
+	for n := node.Parent(); n != nil; n = n.Parent() {
+		if n.Kind() == "arg_values" {
+			return n
+		}
+	}
+	return nil
+}
+
+// closedBefore reports whether argValues has a real closing paren that ends
+// at or before the cursor.
+func closedBefore(argValues *tree_sitter.Node, line, col uint) bool {
+	count := argValues.ChildCount()
+	if count == 0 {
+		return false
+	}
+	last := argValues.Child(count - 1)
+	if last == nil || last.Kind() != ")" || last.IsMissing() {
+		return false
+	}
+	return tsPointBefore(last.EndPosition(), line, col)
+}
+
+// cursorInBlock reports whether the cursor sits inside a block nested within
+// node, e.g. a block argument passed to a call in one of its arguments.
+func cursorInBlock(node *tree_sitter.Node, line, col uint) bool {
+	found := false
+	walkTS(node, func(n *tree_sitter.Node) bool {
+		if found || !tsContains(n.StartPosition(), n.EndPosition(), line, col) {
+			return false
+		}
+		switch n.Kind() {
+		case "block", "block_arg", "object_literal":
+			found = true
+			return false
+		}
+		return true
+	})
+	return found
+}
+
+// cursorInComment reports whether the cursor sits within a comment.
+func cursorInComment(root *tree_sitter.Node, line, col uint) bool {
+	found := false
+	walkTS(root, func(n *tree_sitter.Node) bool {
+		if found || !tsContains(n.StartPosition(), n.EndPosition(), line, col) {
+			return false
+		}
+		if n.Kind() == "comment_token" {
+			found = true
+			return false
+		}
+		return true
+	})
+	return found
+}
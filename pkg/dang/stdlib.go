@@ -2,7 +2,10 @@ package dang
 
 import (
 	"context"
+	"crypto/sha1"
+	"crypto/sha256"
 	"encoding/base64"
+	"encoding/hex"
 	"encoding/json"
 	"fmt"
 	"strings"
@@ -11,10 +14,20 @@ import (
 	"github.com/vito/dang/v2/pkg/ioctx"
 )
 
+// maxRangeLength caps the list range builds, so a runaway bound fails fast
+// instead of exhausting memory.
+const maxRangeLength = 10_000_000
+
+// rangeCancelCheck is how many elements range builds between checks that
+// its context hasn't been canceled.
+const rangeCancelCheck = 1 << 16
+
 // registerStdlib registers all standard library builtins
 // This is called from init() in env.go after type definitions are set up
 func registerStdlib() {
 	registerRandomAndUUID()
+	registerMath()
+	registerTime()
 	registerCodecs()
 	registerAssert()
 	registerRegexp()
@@ -56,6 +69,41 @@ func registerStdlib() {
 			}
 		})
 
+	// range function: range(start: Int!, end: Int!) -> [Int!]!
+	Builtin("range").
+		Doc("returns the integers from start up to, but not including, end; empty when end is not after start, and an error past 10,000,000 integers").
+		Example(`range(0, 3)`).
+		Params(
+			"start", NonNull(IntType),
+			"end", NonNull(IntType),
+		).
+		Returns(NonNull(ListOf(NonNull(IntType)))).
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			start := args.GetInt("start")
+			end := args.GetInt("end")
+			if end <= start {
+				return ListValue{Elements: []Value{}, ElemType: hm.NonNullType{Type: IntType}}, nil
+			}
+			// Subtracting as unsigned can't overflow, even when the bounds
+			// span the whole Int range.
+			if n := uint64(end) - uint64(start); n > maxRangeLength {
+				return nil, fmt.Errorf("range(%d, %d) would have %d elements; at most %d are allowed", start, end, n, maxRangeLength)
+			}
+			elements := make([]Value, 0, end-start)
+			for i := start; i < end; i++ {
+				if (i-start)%rangeCancelCheck == 0 {
+					if err := ctx.Err(); err != nil {
+						return nil, err
+					}
+				}
+				elements = append(elements, IntValue{Val: i})
+			}
+			return ListValue{
+				Elements: elements,
+				ElemType: hm.NonNullType{Type: IntType},
+			}, nil
+		})
+
 	// toString function: toString(value: b) -> String!
 	Builtin("toString").
 		Doc("converts a value to a string, returning strings, enum values, and custom scalar values as-is and serializing other values to JSON").
@@ -167,6 +215,50 @@ func registerStdlib() {
 			return ToValue(string(decoded))
 		})
 
+	// String.toHex method: toHex() -> String!
+	Method(StringType, "toHex").
+		Doc("encodes the string's bytes as a lowercase hexadecimal string").
+		Example(`"hello".toHex`).
+		Returns(NonNull(StringType)).
+		Impl(func(ctx context.Context, self Value, args Args) (Value, error) {
+			str := self.(StringValue).Val
+			return ToValue(hex.EncodeToString([]byte(str)))
+		})
+
+	// String.fromHex method: fromHex() -> String!
+	Method(StringType, "fromHex").
+		Doc("decodes a hexadecimal string, returning the decoded bytes as a string").
+		Example(`"68656c6c6f".fromHex`).
+		Returns(NonNull(StringType)).
+		Impl(func(ctx context.Context, self Value, args Args) (Value, error) {
+			str := self.(StringValue).Val
+			decoded, err := hex.DecodeString(str)
+			if err != nil {
+				return nil, fmt.Errorf("fromHex: %w", err)
+			}
+			return ToValue(string(decoded))
+		})
+
+	// String.sha256 method: sha256() -> String!
+	Method(StringType, "sha256").
+		Doc("returns the hex-encoded SHA-256 digest of the string's bytes").
+		Example(`"hello".sha256`).
+		Returns(NonNull(StringType)).
+		Impl(func(ctx context.Context, self Value, args Args) (Value, error) {
+			sum := sha256.Sum256([]byte(self.(StringValue).Val))
+			return ToValue(hex.EncodeToString(sum[:]))
+		})
+
+	// String.sha1 method: sha1() -> String!
+	Method(StringType, "sha1").
+		Doc("returns the hex-encoded SHA-1 digest of the string's bytes").
+		Example(`"hello".sha1`).
+		Returns(NonNull(StringType)).
+		Impl(func(ctx context.Context, self Value, args Args) (Value, error) {
+			sum := sha1.Sum([]byte(self.(StringValue).Val))
+			return ToValue(hex.EncodeToString(sum[:]))
+		})
+
 	// String.trimPrefix method: trimPrefix(prefix: String!) -> String!
 	Method(StringType, "trimPrefix").
 		Doc("removes the specified prefix from the string if present").
@@ -1175,6 +1267,46 @@ func registerStdlib() {
 			}
 			return MapValue{Keys: append([]string{}, m.Keys...), Entries: entries, ValType: resultValType}, nil
 		})
+
+	// Map.toList method: toList(fn: \(String!, a) -> b) -> [b]!
+	//
+	// GraphQL has no map type, so APIs like Dagger's take key/value data as a
+	// list of input objects (e.g. [BuildArg!]); toList builds one from a map.
+	Method(MapTypeModule, "toList").
+		Doc("returns a list of the block's result for each entry, in insertion order, e.g. to pass a map where a list of key/value input objects is expected").
+		Example(`["a": 1, "b": 2].toList { key, value => key + "=" + toString(value) }`).
+		Block(hm.NewFnType(
+			NewRecordType("", Keyed[*hm.Scheme]{
+				Key:   "key",
+				Value: hm.NewScheme(nil, NonNull(StringType)),
+			}, Keyed[*hm.Scheme]{
+				Key:   "value",
+				Value: hm.NewScheme(nil, TypeVar('a')),
+			}),
+			TypeVar('b'),
+		)).
+		Returns(NonNull(ListOf(TypeVar('b')))).
+		Impl(func(ctx context.Context, self Value, args Args) (Value, error) {
+			m := self.(MapValue)
+			if args.Block == nil {
+				return nil, fmt.Errorf("toList requires a block argument")
+			}
+			fn := *args.Block
+			fnType, ok := fn.Type().(*hm.FunctionType)
+			if !ok {
+				return nil, fmt.Errorf("toList expects a function type, got %T", fn.Type())
+			}
+
+			elems := make([]Value, len(m.Keys))
+			for i, k := range m.Keys {
+				res, err := callFunc(ctx, fn, StringValue{Val: k}, m.Entries[k])
+				if err != nil {
+					return nil, fmt.Errorf("toList block: %w", err)
+				}
+				elems[i] = res
+			}
+			return ListValue{Elements: elems, ElemType: fnType.ReturnType()}, nil
+		})
 }
 
 // callFunc calls a function with the given values as arguments.
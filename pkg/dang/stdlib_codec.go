@@ -4,9 +4,12 @@ import (
 	"bytes"
 	"context"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"io"
+	"strconv"
 	"strings"
+	"unicode/utf8"
 
 	"github.com/BurntSushi/toml"
 	"gopkg.in/yaml.v3"
@@ -62,6 +65,20 @@ func registerCodecs() {
 			}
 			return DeferredValue{Raw: raw, Codec: jsonCodec}, nil
 		})
+	// get is decode for one nested value, for pulling a field or two out of a
+	// document without declaring a type for the whole thing.
+	StaticMethod(JSONModule, "get").
+		Doc("parses a JSON string and returns the value at a dot-separated path (e.g. \"scripts.build\" or \"items.0.name\") as an opaque value materialized by an expected type; a missing path yields null").
+		Example(`JSON.get("""{"name": "dang", "tags": ["a", "b"]}""", "tags.1") :: String!`).
+		Params("data", NonNull(StringType), "path", NonNull(StringType)).
+		Returns(TypeVar('a')).
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			raw, err := decodeJSON(args.GetString("data"))
+			if err != nil {
+				return nil, fmt.Errorf("JSON.get: %w", err)
+			}
+			return DeferredValue{Raw: documentPath(raw, args.GetString("path")), Codec: jsonCodec}, nil
+		})
 
 	// toJSON / fromJSON / fromYAML are the deprecated top-level predecessors of
 	// the JSON.encode / JSON.decode / YAML.decode namespace members, kept so
@@ -126,6 +143,18 @@ func registerCodecs() {
 			}
 			return DeferredValue{Raw: raw, Codec: yamlCodec}, nil
 		})
+	StaticMethod(YAMLModule, "get").
+		Doc("parses a YAML string and returns the value at a dot-separated path (e.g. \"spec.replicas\" or \"containers.0.image\") as an opaque value materialized by an expected type; a missing path yields null").
+		Example("YAML.get(\"name: dang\\ntags: [a, b]\", \"tags.1\") :: String!").
+		Params("data", NonNull(StringType), "path", NonNull(StringType)).
+		Returns(TypeVar('a')).
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			raw, err := decodeYAMLRaw(args.GetString("data"))
+			if err != nil {
+				return nil, fmt.Errorf("YAML.get: %w", err)
+			}
+			return DeferredValue{Raw: documentPath(raw, args.GetString("path")), Codec: yamlCodec}, nil
+		})
 	Builtin("fromYAML").
 		Doc("parses a YAML string into an opaque value that is materialized by an expected type. Deprecated: use YAML.decode instead.").
 		Deprecated("use YAML.decode instead").
@@ -167,6 +196,18 @@ func registerCodecs() {
 			}
 			return DeferredValue{Raw: raw, Codec: tomlCodec}, nil
 		})
+	StaticMethod(TOMLModule, "get").
+		Doc("parses a TOML string and returns the value at a dot-separated path (e.g. \"package.version\") as an opaque value materialized by an expected type; a missing path yields null").
+		Example("TOML.get(\"[package]\\nname = \\\"dang\\\"\", \"package.name\") :: String!").
+		Params("data", NonNull(StringType), "path", NonNull(StringType)).
+		Returns(TypeVar('a')).
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			raw, err := decodeTOML(args.GetString("data"))
+			if err != nil {
+				return nil, fmt.Errorf("TOML.get: %w", err)
+			}
+			return DeferredValue{Raw: documentPath(raw, args.GetString("path")), Codec: tomlCodec}, nil
+		})
 
 	registerCodecFieldDirectives()
 }
@@ -194,20 +235,80 @@ func decodeJSON(data string) (any, error) {
 
 	var raw any
 	if err := decoder.Decode(&raw); err != nil {
-		return nil, fmt.Errorf("invalid JSON: %w", err)
+		return nil, invalidJSON(err, data)
 	}
 
+	end := decoder.InputOffset()
 	var extra any
 	if err := decoder.Decode(&extra); err != io.EOF {
 		if err != nil {
-			return nil, fmt.Errorf("invalid JSON: %w", err)
+			return nil, invalidJSON(err, data)
 		}
-		return nil, fmt.Errorf("invalid JSON: trailing data")
+		return nil, fmt.Errorf("invalid JSON at byte offset %d: trailing data", end)
 	}
 
 	return raw, nil
 }
 
+// invalidJSON reports a decode error along with the line, column, and
+// zero-based byte offset it occurred at, so a bad document can be found in a
+// long input.
+func invalidJSON(err error, data string) error {
+	var syntaxErr *json.SyntaxError
+	if errors.As(err, &syntaxErr) {
+		// Offset counts the bytes read, including the offending one.
+		offset := max(int(syntaxErr.Offset)-1, 0)
+		line, col := offsetLineColumn(data, offset)
+		return fmt.Errorf("invalid JSON at line %d, column %d (byte offset %d): %w", line, col, offset, err)
+	}
+	if errors.Is(err, io.ErrUnexpectedEOF) {
+		line, col := offsetLineColumn(data, len(data))
+		return fmt.Errorf("invalid JSON at line %d, column %d (byte offset %d): unexpected end of input", line, col, len(data))
+	}
+	return fmt.Errorf("invalid JSON: %w", err)
+}
+
+// offsetLineColumn converts a byte offset into data to a 1-based line and
+// column, counting columns in characters.
+func offsetLineColumn(data string, offset int) (int, int) {
+	offset = min(offset, len(data))
+	before := data[:offset]
+	line := strings.Count(before, "\n") + 1
+	lineStart := strings.LastIndexByte(before, '\n') + 1
+	return line, utf8.RuneCountInString(before[lineStart:]) + 1
+}
+
+// documentPath walks a decoded document along a dot-separated path, where a
+// segment names an object key or, numerically, an array index. JSON, YAML, and
+// TOML all decode to the same JSON-like shape, so this serves each format's
+// get. It returns nil when the path doesn't exist, so the result materializes
+// as null.
+func documentPath(raw any, path string) any {
+	if path == "" {
+		return raw
+	}
+	cur := raw
+	for _, seg := range strings.Split(path, ".") {
+		switch node := cur.(type) {
+		case map[string]any:
+			next, ok := node[seg]
+			if !ok {
+				return nil
+			}
+			cur = next
+		case []any:
+			i, err := strconv.Atoi(seg)
+			if err != nil || i < 0 || i >= len(node) {
+				return nil
+			}
+			cur = node[i]
+		default:
+			return nil
+		}
+	}
+	return cur
+}
+
 // --- YAML ---
 
 func encodeYAML(val Value) (string, error) {
@@ -228,6 +329,13 @@ func encodeYAML(val Value) (string, error) {
 func decodeYAMLRaw(data string) (any, error) {
 	raw, err := decodeYAML(data)
 	if err != nil {
+		// The YAML parser reports only the line of a syntax error, as a
+		// "yaml: line N: " prefix.
+		var line int
+		if _, scanErr := fmt.Sscanf(err.Error(), "yaml: line %d:", &line); scanErr == nil {
+			msg := strings.TrimPrefix(err.Error(), fmt.Sprintf("yaml: line %d: ", line))
+			return nil, fmt.Errorf("invalid YAML at line %d: %s", line, msg)
+		}
 		return nil, fmt.Errorf("invalid YAML: %w", err)
 	}
 	return raw, nil
@@ -260,6 +368,11 @@ func encodeTOML(val Value) (string, error) {
 func decodeTOML(data string) (any, error) {
 	var m map[string]any
 	if _, err := toml.Decode(data, &m); err != nil {
+		var parseErr toml.ParseError
+		if errors.As(err, &parseErr) {
+			line, col := offsetLineColumn(data, parseErr.Position.Start)
+			return nil, fmt.Errorf("invalid TOML at line %d, column %d: %s", line, col, parseErr.Message)
+		}
 		return nil, fmt.Errorf("invalid TOML: %w", err)
 	}
 	// Bridge through JSON so the decoded shape matches what the materializer
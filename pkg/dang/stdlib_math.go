@@ -0,0 +1,268 @@
+package dang
+
+import (
+	"cmp"
+	"context"
+	"fmt"
+	"math"
+)
+
+// MathModule is the "Math" namespace for numeric helpers
+var MathModule = NewType("Math", ObjectKind)
+
+func registerMath() {
+	MathModule.SetTypeDocString("functions and constants for working with numbers")
+
+	// Math.pi -> Float!
+	StaticMethod(MathModule, "pi").
+		Doc("the ratio of a circle's circumference to its diameter").
+		Example(`Math.pi`).
+		Returns(NonNull(FloatType)).
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			return ToValue(math.Pi)
+		})
+
+	// Math.e -> Float!
+	StaticMethod(MathModule, "e").
+		Doc("the base of the natural logarithm").
+		Example(`Math.e`).
+		Returns(NonNull(FloatType)).
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			return ToValue(math.E)
+		})
+
+	// The helpers below take either Int or Float. There's no way to spell
+	// "any number" in a builtin signature, so their parameters are type
+	// variables and Numeric has inference reject everything else.
+
+	// Math.min(a: a, b: b) -> a, or Float! when a and b mix Int and Float
+	StaticMethod(MathModule, "min").
+		Doc("returns the smaller of two numbers").
+		Example(`Math.min(3, 7)`).
+		Params("a", TypeVar('a'), "b", TypeVar('b')).
+		Returns(TypeVar('a')).
+		Numeric().
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			c, err := compareNumbers("Math.min", args)
+			if err != nil {
+				return nil, err
+			}
+			if c <= 0 {
+				return promoteNumber(args.Values["a"], args.Values["b"]), nil
+			}
+			return promoteNumber(args.Values["b"], args.Values["a"]), nil
+		})
+
+	// Math.max(a: a, b: b) -> a, or Float! when a and b mix Int and Float
+	StaticMethod(MathModule, "max").
+		Doc("returns the larger of two numbers").
+		Example(`Math.max(3, 7)`).
+		Params("a", TypeVar('a'), "b", TypeVar('b')).
+		Returns(TypeVar('a')).
+		Numeric().
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			c, err := compareNumbers("Math.max", args)
+			if err != nil {
+				return nil, err
+			}
+			if c >= 0 {
+				return promoteNumber(args.Values["a"], args.Values["b"]), nil
+			}
+			return promoteNumber(args.Values["b"], args.Values["a"]), nil
+		})
+
+	// Math.abs(value: a) -> a
+	StaticMethod(MathModule, "abs").
+		Doc("returns the absolute value of a number").
+		Example(`Math.abs(-5)`).
+		Params("value", TypeVar('a')).
+		Returns(TypeVar('a')).
+		Numeric().
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			switch v := args.Values["value"].(type) {
+			case IntValue:
+				if v.Val == math.MinInt {
+					return nil, fmt.Errorf("Math.abs: %d has no Int absolute value", v.Val)
+				}
+				if v.Val < 0 {
+					return IntValue{Val: -v.Val}, nil
+				}
+				return v, nil
+			case FloatValue:
+				return FloatValue{Val: math.Abs(v.Val)}, nil
+			}
+			return nil, notANumber("Math.abs", args, "value")
+		})
+
+	// Math.floor(value: a) -> Int!
+	StaticMethod(MathModule, "floor").
+		Doc("rounds a number down to the nearest integer").
+		Example(`Math.floor(2.7)`).
+		Params("value", TypeVar('a')).
+		Returns(NonNull(IntType)).
+		Numeric().
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			return roundNumber("Math.floor", args, math.Floor)
+		})
+
+	// Math.ceil(value: a) -> Int!
+	StaticMethod(MathModule, "ceil").
+		Doc("rounds a number up to the nearest integer").
+		Example(`Math.ceil(2.1)`).
+		Params("value", TypeVar('a')).
+		Returns(NonNull(IntType)).
+		Numeric().
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			return roundNumber("Math.ceil", args, math.Ceil)
+		})
+
+	// Math.round(value: a) -> Int!
+	StaticMethod(MathModule, "round").
+		Doc("rounds a number to the nearest integer, rounding halves away from zero").
+		Example(`Math.round(2.5)`).
+		Params("value", TypeVar('a')).
+		Returns(NonNull(IntType)).
+		Numeric().
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			return roundNumber("Math.round", args, math.Round)
+		})
+
+	// Math.toInt(value: a) -> Int!
+	StaticMethod(MathModule, "toInt").
+		Doc("converts a number to an Int, truncating any fractional part toward zero").
+		Example(`Math.toInt(-2.7)`).
+		Params("value", TypeVar('a')).
+		Returns(NonNull(IntType)).
+		Numeric().
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			return roundNumber("Math.toInt", args, math.Trunc)
+		})
+
+	// Math.toFloat(value: a) -> Float!
+	StaticMethod(MathModule, "toFloat").
+		Doc("converts a number to a Float").
+		Example(`Math.toFloat(3)`).
+		Params("value", TypeVar('a')).
+		Returns(NonNull(FloatType)).
+		Numeric().
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			x, err := numberArg("Math.toFloat", args, "value")
+			if err != nil {
+				return nil, err
+			}
+			return ToValue(x)
+		})
+
+	// Math.pow(base: a, exponent: b) -> Float!
+	StaticMethod(MathModule, "pow").
+		Doc("raises base to the power of exponent").
+		Example(`Math.pow(2, 10)`).
+		Params("base", TypeVar('a'), "exponent", TypeVar('b')).
+		Returns(NonNull(FloatType)).
+		Numeric().
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			base, err := numberArg("Math.pow", args, "base")
+			if err != nil {
+				return nil, err
+			}
+			exp, err := numberArg("Math.pow", args, "exponent")
+			if err != nil {
+				return nil, err
+			}
+			if base == 0 && exp < 0 {
+				return nil, fmt.Errorf("Math.pow: %v to a negative power is a division by zero", base)
+			}
+			result := math.Pow(base, exp)
+			if math.IsNaN(result) {
+				return nil, fmt.Errorf("Math.pow: %v to the power of %v is not a real number", base, exp)
+			}
+			if math.IsInf(result, 0) {
+				return nil, fmt.Errorf("Math.pow: %v to the power of %v is too large", base, exp)
+			}
+			return ToValue(result)
+		})
+
+	// Math.sqrt(value: a) -> Float!
+	StaticMethod(MathModule, "sqrt").
+		Doc("returns the square root of a number").
+		Example(`Math.sqrt(16)`).
+		Params("value", TypeVar('a')).
+		Returns(NonNull(FloatType)).
+		Numeric().
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			x, err := numberArg("Math.sqrt", args, "value")
+			if err != nil {
+				return nil, err
+			}
+			if x < 0 {
+				return nil, fmt.Errorf("Math.sqrt: cannot take the square root of a negative number (%v)", x)
+			}
+			return ToValue(math.Sqrt(x))
+		})
+}
+
+// numberArg returns an Int or Float argument as a float64.
+func numberArg(fn string, args Args, name string) (float64, error) {
+	switch v := args.Values[name].(type) {
+	case IntValue:
+		return float64(v.Val), nil
+	case FloatValue:
+		return v.Val, nil
+	}
+	return 0, notANumber(fn, args, name)
+}
+
+func notANumber(fn string, args Args, name string) error {
+	val := args.Values[name]
+	if val == nil {
+		val = NullValue{}
+	}
+	return fmt.Errorf("%s: %s must be an Int or Float, got %s", fn, name, val.Type())
+}
+
+// compareNumbers compares the a and b arguments. Two Ints are compared
+// exactly rather than through float64, which can't represent every Int.
+func compareNumbers(fn string, args Args) (int, error) {
+	if a, ok := args.Values["a"].(IntValue); ok {
+		if b, ok := args.Values["b"].(IntValue); ok {
+			return cmp.Compare(a.Val, b.Val), nil
+		}
+	}
+	a, err := numberArg(fn, args, "a")
+	if err != nil {
+		return 0, err
+	}
+	b, err := numberArg(fn, args, "b")
+	if err != nil {
+		return 0, err
+	}
+	return cmp.Compare(a, b), nil
+}
+
+// promoteNumber returns val as a Float when other is one, matching the
+// Float! that min and max infer for mixed arguments.
+func promoteNumber(val, other Value) Value {
+	if i, ok := val.(IntValue); ok {
+		if _, ok := other.(FloatValue); ok {
+			return FloatValue{Val: float64(i.Val)}
+		}
+	}
+	return val
+}
+
+// roundNumber rounds the value argument to an Int with round. Ints are
+// already whole and pass through untouched.
+func roundNumber(fn string, args Args, round func(float64) float64) (Value, error) {
+	if v, ok := args.Values["value"].(IntValue); ok {
+		return v, nil
+	}
+	x, err := numberArg(fn, args, "value")
+	if err != nil {
+		return nil, err
+	}
+	r := round(x)
+	if math.IsNaN(r) || r < math.MinInt64 || r >= math.MaxInt64 {
+		return nil, fmt.Errorf("%s: %v is out of range for Int", fn, x)
+	}
+	return ToValue(int(r))
+}
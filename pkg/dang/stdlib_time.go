@@ -0,0 +1,69 @@
+package dang
+
+import (
+	"context"
+	"fmt"
+	"math"
+	"time"
+)
+
+// TimeModule is the "Time" namespace for timestamps and durations
+var TimeModule = NewType("Time", ObjectKind)
+
+func registerTime() {
+	TimeModule.SetTypeDocString("functions for working with timestamps and durations")
+
+	// Time.now() -> String!
+	StaticMethod(TimeModule, "now").
+		Doc("returns the current time as an ISO-8601 string in UTC; the result differs on every run, so it is not cacheable").
+		Example(`Time.now`).
+		Returns(NonNull(StringType)).
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			return ToValue(time.Now().UTC().Format(time.RFC3339))
+		})
+
+	// Time.unix() -> Int!
+	StaticMethod(TimeModule, "unix").
+		Doc("returns the current time in seconds since the Unix epoch; the result differs on every run, so it is not cacheable").
+		Example(`Time.unix`).
+		Returns(NonNull(IntType)).
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			return ToValue(time.Now().Unix())
+		})
+
+	// Time.format(timestamp: Int!, layout: String!) -> String!
+	StaticMethod(TimeModule, "format").
+		Doc("renders a Unix timestamp in UTC using a Go time layout, e.g. \"2006-01-02 15:04:05\"").
+		Example(`Time.format(0, "2006-01-02")`).
+		Params("timestamp", NonNull(IntType), "layout", NonNull(StringType)).
+		Returns(NonNull(StringType)).
+		Impl(func(ctx context.Context, args Args) (Value, error) {
+			ts := time.Unix(int64(args.GetInt("timestamp")), 0).UTC()
+			return ToValue(ts.Format(args.GetString("layout")))
+		})
+
+	// Durations are rendered the way Go prints them ("1m30s"), which is the
+	// form Dagger parses wherever it takes a duration string.
+	for _, unit := range []struct {
+		name     string
+		duration time.Duration
+	}{
+		{"milliseconds", time.Millisecond},
+		{"seconds", time.Second},
+		{"minutes", time.Minute},
+		{"hours", time.Hour},
+	} {
+		StaticMethod(TimeModule, unit.name).
+			Doc("returns a duration of n "+unit.name+", e.g. \""+(2*unit.duration).String()+"\" for 2").
+			Example(`Time.`+unit.name+`(2)`).
+			Params("n", NonNull(IntType)).
+			Returns(NonNull(StringType)).
+			Impl(func(ctx context.Context, args Args) (Value, error) {
+				n := args.GetInt("n")
+				if limit := int(math.MaxInt64 / unit.duration); n > limit || n < -limit {
+					return nil, fmt.Errorf("Time.%s: %w: %d %s is longer than a duration can hold", unit.name, errIntOverflow, n, unit.name)
+				}
+				return ToValue((time.Duration(n) * unit.duration).String())
+			})
+	}
+}
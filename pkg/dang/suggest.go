@@ -0,0 +1,83 @@
+package dang
+
+import (
+	"cmp"
+	"fmt"
+	"iter"
+	"slices"
+	"strings"
+)
+
+// maxNameCandidates is how many of the closest names are listed when none is
+// close enough to suggest outright.
+const maxNameCandidates = 3
+
+// nameSuggestion renders a note line for a name that wasn't found among
+// candidates: "did you mean `x`?" when one candidate is within a typo's edit
+// distance, otherwise the closest few. Empty when there are no candidates, so
+// callers can append it unconditionally.
+func nameSuggestion(name string, candidates iter.Seq[string]) string {
+	type candidate struct {
+		name     string
+		distance int
+	}
+	var ranked []candidate
+	for c := range candidates {
+		if c == name {
+			continue
+		}
+		ranked = append(ranked, candidate{c, editDistance(strings.ToLower(name), strings.ToLower(c))})
+	}
+	if len(ranked) == 0 {
+		return ""
+	}
+	slices.SortFunc(ranked, func(a, b candidate) int {
+		return cmp.Or(cmp.Compare(a.distance, b.distance), cmp.Compare(a.name, b.name))
+	})
+
+	// Allow roughly one edit per three characters, so short names don't
+	// match everything.
+	if ranked[0].distance <= max(1, len(name)/3) {
+		return fmt.Sprintf("\n  did you mean `%s`?", ranked[0].name)
+	}
+
+	closest := make([]string, 0, maxNameCandidates)
+	for _, c := range ranked[:min(len(ranked), maxNameCandidates)] {
+		closest = append(closest, "`"+c.name+"`")
+	}
+	return "\n  closest matches: " + strings.Join(closest, ", ")
+}
+
+// editDistance is the Levenshtein distance between a and b.
+func editDistance(a, b string) int {
+	ar, br := []rune(a), []rune(b)
+	prev := make([]int, len(br)+1)
+	cur := make([]int, len(br)+1)
+	for j := range prev {
+		prev[j] = j
+	}
+	for i := 1; i <= len(ar); i++ {
+		cur[0] = i
+		for j := 1; j <= len(br); j++ {
+			cost := 1
+			if ar[i-1] == br[j-1] {
+				cost = 0
+			}
+			cur[j] = min(prev[j]+1, cur[j-1]+1, prev[j-1]+cost)
+		}
+		prev, cur = cur, prev
+	}
+	return prev[len(br)]
+}
+
+// bindingNames returns the names bound in a type scope at the given
+// visibility.
+func bindingNames(scope TypeScope, visibility Visibility) iter.Seq[string] {
+	return func(yield func(string) bool) {
+		for name := range scope.Bindings(visibility) {
+			if !yield(name) {
+				return
+			}
+		}
+	}
+}
@@ -0,0 +1,95 @@
+package dang
+
+import (
+	"context"
+	"errors"
+	"slices"
+	"strings"
+	"time"
+)
+
+// TestResult is the outcome of one test function run by RunTests.
+type TestResult struct {
+	Name     string
+	Duration time.Duration
+	// Err is why the test failed, or nil if it passed.
+	Err error
+}
+
+// Passed reports whether the test neither raised an error nor returned false.
+func (r TestResult) Passed() bool {
+	return r.Err == nil
+}
+
+// RunTests evaluates the module in dirPath and then calls each of its test
+// functions: public functions whose name starts with "test" and that need no
+// arguments. Only tests whose name contains filter are run, in name order. A
+// test fails if it raises an error, e.g. from a failed assert, or returns
+// false; any other result passes.
+//
+// An error is returned only when the module itself fails to type-check or
+// evaluate, in which case no tests run.
+func RunTests(ctx context.Context, dirPath string, filter string) ([]TestResult, error) {
+	// Keep services alive until every test has run, rather than letting RunDir
+	// stop them once the module is evaluated.
+	ctx, services := ensureServiceRegistry(ctx)
+	if services != nil {
+		defer services.StopAll()
+	}
+
+	scope, err := RunDir(ctx, dirPath, false)
+	if err != nil {
+		return nil, err
+	}
+
+	evalCtx := NewEvalContext(dirPath, "")
+	ctx = WithEvalContext(ctx, evalCtx)
+
+	var results []TestResult
+	for _, test := range testFunctions(scope, filter) {
+		start := time.Now()
+		err := runTest(ctx, scope, test.Value)
+		if err != nil {
+			if translated, ok := translateBoundaryEvalError(err, evalCtx); ok {
+				err = translated
+			}
+		}
+		results = append(results, TestResult{
+			Name:     test.Key,
+			Duration: time.Since(start),
+			Err:      err,
+		})
+	}
+	return results, nil
+}
+
+// testFunctions returns the module's test functions matching filter, sorted
+// by name.
+func testFunctions(scope ValueScope, filter string) []Keyed[FunctionValue] {
+	var tests []Keyed[FunctionValue]
+	for _, kv := range scope.Bindings(PublicVisibility) {
+		if !strings.HasPrefix(kv.Key, "test") || !strings.Contains(kv.Key, filter) {
+			continue
+		}
+		fn, ok := kv.Value.(FunctionValue)
+		if !ok || !fn.IsAutoCallable() {
+			continue
+		}
+		tests = append(tests, Keyed[FunctionValue]{Key: kv.Key, Value: fn})
+	}
+	slices.SortFunc(tests, func(a, b Keyed[FunctionValue]) int {
+		return strings.Compare(a.Key, b.Key)
+	})
+	return tests
+}
+
+func runTest(ctx context.Context, scope ValueScope, fn FunctionValue) error {
+	result, err := fn.Call(ctx, scope, map[string]Value{})
+	if err != nil {
+		return err
+	}
+	if b, ok := result.(BoolValue); ok && !b.Val {
+		return NewSourceError(errors.New("test returned false"), fn.Body.GetSourceLocation(), "")
+	}
+	return nil
+}
@@ -0,0 +1,59 @@
+package dang
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestRunTests(t *testing.T) {
+	dir := t.TempDir()
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "lib.dang"), []byte(`
+double(x: Int!): Int! { x * 2 }
+`), 0o600))
+	require.NoError(t, os.WriteFile(filepath.Join(dir, "lib_test.dang"), []byte(`
+testDouble: Boolean! { double(2) == 4 }
+
+testDoubleAssert: Int! {
+  assert { double(3) == 7 }
+  1
+}
+
+testReturnsFalse: Boolean! { double(1) == 3 }
+
+testWithDefault(n: Int! = 5): Boolean! { double(n) == 10 }
+
+testNeedsArg(n: Int!): Boolean! { true }
+
+let testPrivate: Boolean! { false }
+
+helper: Boolean! { false }
+`), 0o600))
+
+	results, err := RunTests(context.Background(), dir, "")
+	require.NoError(t, err)
+
+	outcomes := map[string]bool{}
+	var names []string
+	for _, r := range results {
+		names = append(names, r.Name)
+		outcomes[r.Name] = r.Passed()
+	}
+	require.Equal(t, []string{"testDouble", "testDoubleAssert", "testReturnsFalse", "testWithDefault"}, names)
+	require.True(t, outcomes["testDouble"])
+	require.False(t, outcomes["testDoubleAssert"])
+	require.False(t, outcomes["testReturnsFalse"])
+	require.True(t, outcomes["testWithDefault"])
+
+	// A failed assert is rendered with its source span.
+	var sourceErr *SourceError
+	require.ErrorAs(t, results[1].Err, &sourceErr)
+	require.Contains(t, results[1].Err.Error(), "lib_test.dang")
+
+	filtered, err := RunTests(context.Background(), dir, "Double")
+	require.NoError(t, err)
+	require.Len(t, filtered, 2)
+}
@@ -0,0 +1,71 @@
+package dang
+
+import (
+	"context"
+	"fmt"
+	"strings"
+)
+
+// warnUnusedLets warns about each `let` among a block's forms that no other
+// form reads, which usually means a value was bound to the wrong name. A name
+// starting with `_` opts out.
+//
+// Only the forms after a binding can read it, and a later `let` of the same
+// name shadows it for the rest of the block, so only the forms up to and
+// including the shadowing one count.
+// The block's last form is its value, so it counts as read. Reads are
+// collected generously (any symbol of the same name, at any depth), so a
+// warning is only reported when the name can't be referenced at all.
+func warnUnusedLets(ctx context.Context, forms []Node) {
+	if len(forms) < 2 {
+		return
+	}
+	refs := make([]map[string]bool, len(forms))
+	for i, form := range forms {
+		refs[i] = formReferences(form)
+	}
+	for i, form := range forms[:len(forms)-1] {
+		decl, ok := form.(*FieldDecl)
+		if !ok || decl.Visibility != PrivateVisibility || strings.HasPrefix(decl.Name.Name, "_") {
+			continue
+		}
+		name := decl.Name.Name
+		used := false
+		for j := i + 1; j < len(forms); j++ {
+			if refs[j][name] {
+				used = true
+				break
+			}
+			if declaresLet(forms[j], name) {
+				break
+			}
+		}
+		if !used {
+			EmitInferWarning(ctx, decl.Name, fmt.Sprintf("unused binding `%s`: it is never read; rename it to `_%s` if that's intended", name, name))
+		}
+	}
+}
+
+// formReferences returns the names a form may read. A declaration's walk
+// doesn't visit its own name, so declaring a name isn't a read of it.
+func formReferences(form Node) map[string]bool {
+	refs := map[string]bool{}
+	for _, name := range form.ReferencedSymbols() {
+		refs[name] = true
+	}
+	form.Walk(func(n Node) bool {
+		if n == nil {
+			return false
+		}
+		if sym, ok := n.(*Symbol); ok {
+			refs[sym.Name] = true
+		}
+		return true
+	})
+	return refs
+}
+
+func declaresLet(form Node, name string) bool {
+	decl, ok := form.(*FieldDecl)
+	return ok && decl.Name.Name == name
+}
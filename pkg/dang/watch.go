@@ -0,0 +1,117 @@
+package dang
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io/fs"
+	"maps"
+	"os"
+	"path/filepath"
+	"time"
+)
+
+// watchInterval is how often Watch polls for changes. It doubles as the
+// debounce window: a re-check starts once a poll sees no further changes.
+const watchInterval = 250 * time.Millisecond
+
+// Watch type-checks path like Check, then re-checks it whenever one of the
+// files it depends on changes, until ctx is done. Each result is passed to
+// report; a nil error means the check came back clean.
+//
+// The files watched are the .dang files of a directory module (or the single
+// file being checked) and the dang.toml beside them. Changes are found by
+// polling, and a burst of saves triggers a single re-check once it settles.
+// Only a path that's missing to begin with is an error; one that goes away
+// later is waited on until it's back.
+func Watch(ctx context.Context, path string, report func(error)) error {
+	return watch(ctx, path, watchInterval, report)
+}
+
+func watch(ctx context.Context, path string, interval time.Duration, report func(error)) error {
+	seen, err := watchedFiles(path)
+	if err != nil {
+		return err
+	}
+	report(Check(ctx, path))
+
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return nil
+		case <-ticker.C:
+		}
+		current, err := watchedFiles(path)
+		if errors.Is(err, fs.ErrNotExist) {
+			// The path is gone, e.g. mid-rename by an editor saving
+			// atomically; wait for it to come back.
+			continue
+		}
+		if err != nil {
+			return err
+		}
+		if maps.Equal(current, seen) {
+			continue
+		}
+		// Wait for the files to stop changing, so that saving several files
+		// (or an editor writing one in steps) is checked once.
+		for {
+			select {
+			case <-ctx.Done():
+				return nil
+			case <-ticker.C:
+			}
+			next, err := watchedFiles(path)
+			if errors.Is(err, fs.ErrNotExist) {
+				continue
+			}
+			if err != nil {
+				return err
+			}
+			if maps.Equal(next, current) {
+				break
+			}
+			current = next
+		}
+		seen = current
+		report(Check(ctx, path))
+	}
+}
+
+// fileStamp identifies a version of a watched file.
+type fileStamp struct {
+	modTime time.Time
+	size    int64
+}
+
+// watchedFiles stamps each file a check of path depends on.
+func watchedFiles(path string) (map[string]fileStamp, error) {
+	info, err := os.Stat(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to access path %s: %w", path, err)
+	}
+	dir := filepath.Dir(path)
+	files := []string{path}
+	if info.IsDir() {
+		dir = path
+		files, err = filepath.Glob(filepath.Join(path, "*.dang"))
+		if err != nil {
+			return nil, err
+		}
+	}
+	files = append(files, filepath.Join(dir, "dang.toml"))
+
+	stamps := make(map[string]fileStamp, len(files))
+	for _, file := range files {
+		info, err := os.Stat(file)
+		if err != nil {
+			// A file that's missing (or mid-rename) is just absent; it shows
+			// up as a change once it's back.
+			continue
+		}
+		stamps[file] = fileStamp{modTime: info.ModTime(), size: info.Size()}
+	}
+	return stamps, nil
+}
@@ -0,0 +1,94 @@
+package dang
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestWatchRechecksOnChange(t *testing.T) {
+	dir := t.TempDir()
+	file := filepath.Join(dir, "main.dang")
+	require.NoError(t, os.WriteFile(file, []byte("x: Int! = 1\n"), 0o644))
+
+	ctx, cancel := context.WithCancel(context.Background())
+	results := make(chan error, 4)
+	done := make(chan error)
+	go func() {
+		done <- watch(ctx, dir, 10*time.Millisecond, func(err error) { results <- err })
+	}()
+
+	next := func() error {
+		t.Helper()
+		select {
+		case err := <-results:
+			return err
+		case <-time.After(10 * time.Second):
+			t.Fatal("timed out waiting for a check")
+			return nil
+		}
+	}
+
+	require.NoError(t, next(), "the first check should be clean")
+
+	require.NoError(t, os.WriteFile(file, []byte("x: Int! = \"one\"\n"), 0o644))
+	require.Error(t, next(), "a broken edit should be reported")
+
+	require.NoError(t, os.WriteFile(file, []byte("x: Int! = 2\n"), 0o644))
+	require.NoError(t, next(), "fixing the edit should clear the error")
+
+	cancel()
+	require.NoError(t, <-done)
+}
+
+func TestWatchWaitsForMissingPath(t *testing.T) {
+	dir := t.TempDir()
+	file := filepath.Join(dir, "main.dang")
+	require.NoError(t, os.WriteFile(file, []byte("x: Int! = 1\n"), 0o644))
+
+	ctx, cancel := context.WithCancel(context.Background())
+	results := make(chan error, 4)
+	done := make(chan error)
+	go func() {
+		done <- watch(ctx, file, 10*time.Millisecond, func(err error) { results <- err })
+	}()
+
+	next := func() error {
+		t.Helper()
+		select {
+		case err := <-results:
+			return err
+		case <-time.After(10 * time.Second):
+			t.Fatal("timed out waiting for a check")
+			return nil
+		}
+	}
+
+	require.NoError(t, next(), "the first check should be clean")
+
+	// Replace the file the way an editor saving atomically would, leaving it
+	// missing for a few polls.
+	require.NoError(t, os.Remove(file))
+	time.Sleep(50 * time.Millisecond)
+	select {
+	case err := <-done:
+		t.Fatalf("watch stopped while the file was missing: %v", err)
+	default:
+	}
+	require.NoError(t, os.WriteFile(file, []byte("x: Int! = \"one\"\n"), 0o644))
+	require.Error(t, next(), "the replaced file should be checked")
+
+	cancel()
+	require.NoError(t, <-done)
+}
+
+func TestWatchMissingPath(t *testing.T) {
+	err := watch(context.Background(), filepath.Join(t.TempDir(), "missing.dang"), time.Millisecond, func(error) {
+		t.Error("a missing path shouldn't be checked")
+	})
+	require.ErrorIs(t, err, os.ErrNotExist)
+}
@@ -31,6 +31,20 @@ func positionWithinNode(node dang.Node, pos Position) bool {
 		(pos.Line < endLine || (pos.Line == endLine && pos.Character <= endCol))
 }
 
+// nodeRange returns the LSP range covering a node's whole source span.
+func nodeRange(node dang.Node) (Range, bool) {
+	loc := node.GetSourceLocation()
+	if loc == nil {
+		return Range{}, false
+	}
+	start := Position{Line: loc.Line - 1, Character: loc.Column - 1}
+	end := Position{Line: start.Line, Character: start.Character + loc.Length}
+	if loc.End != nil {
+		end = Position{Line: loc.End.Line - 1, Character: loc.End.Column - 1}
+	}
+	return Range{Start: start, End: end}, true
+}
+
 // positionAfter reports whether p comes strictly after q in a document.
 func positionAfter(p, q Position) bool {
 	if p.Line != q.Line {
@@ -93,3 +107,45 @@ func findEnclosingEnvironments(root dang.Node, pos Position) []dang.TypeScope {
 
 	return environments
 }
+
+// declarationName returns the symbol naming a declaration node, or nil if
+// the node doesn't declare a single name.
+func declarationName(node dang.Node) *dang.Symbol {
+	switch n := node.(type) {
+	case *dang.FieldDecl:
+		return n.Name
+	case *dang.ObjectDecl:
+		return n.Name
+	case *dang.EnumDecl:
+		return n.Name
+	case *dang.InterfaceDecl:
+		return n.Name
+	case *dang.UnionDecl:
+		return n.Name
+	case *dang.ScalarDecl:
+		return n.Name
+	}
+	return nil
+}
+
+// fieldNames returns the names of decls.
+func fieldNames(decls []*dang.FieldDecl) []*dang.Symbol {
+	var names []*dang.Symbol
+	for _, decl := range decls {
+		if decl != nil && decl.Name != nil {
+			names = append(names, decl.Name)
+		}
+	}
+	return names
+}
+
+// formNames returns the names declared by forms.
+func formNames(forms []dang.Node) []*dang.Symbol {
+	var names []*dang.Symbol
+	for _, form := range forms {
+		if sym := declarationName(form); sym != nil {
+			names = append(names, sym)
+		}
+	}
+	return names
+}
@@ -34,12 +34,26 @@ func (h *langHandler) handleInitialize(ctx context.Context, req *jrpc2.Request)
 			CompletionProvider: &CompletionProvider{
 				TriggerCharacters: []string{"."},
 			},
-			DefinitionProvider:         true,
-			HoverProvider:              true,
-			CodeActionProvider:         true,
-			RenameProvider:             true,
-			WorkspaceSymbolProvider:    true,
-			DocumentFormattingProvider: true,
+			DefinitionProvider:              true,
+			DocumentSymbolProvider:          true,
+			HoverProvider:                   true,
+			ReferencesProvider:              true,
+			CodeActionProvider:              true,
+			RenameProvider:                  true,
+			WorkspaceSymbolProvider:         true,
+			DocumentFormattingProvider:      true,
+			DocumentRangeFormattingProvider: true,
+			SemanticTokensProvider: &SemanticTokensOptions{
+				Legend: semanticTokensLegend,
+				Range:  true,
+				Full:   true,
+			},
+			InlayHintProvider: &InlayHintOptions{
+				ResolveProvider: true,
+			},
+			SignatureHelpProvider: &SignatureHelpOptions{
+				TriggerCharacters: []string{"(", ","},
+			},
 			Workspace: &ServerCapabilitiesWorkspace{
 				WorkspaceFolders: WorkspaceFoldersServerCapabilities{
 					Supported:           true,
@@ -68,6 +68,19 @@ func (h *langHandler) handleTextDocumentCodeAction(ctx context.Context, req *jrp
 		})
 	}
 
+	// Offer to import a name the file references but doesn't import. Clients
+	// echo the diagnostics they were sent, but fall back to the ones we
+	// published in case a client drops their data.
+	imported := map[string]bool{}
+	for _, diag := range append(params.Context.Diagnostics, f.Diagnostics...) {
+		imp, ok := missingImportOf(diag)
+		if !ok || imported[imp.Import] || !rangesOverlap(diag.Range, params.Range) {
+			continue
+		}
+		imported[imp.Import] = true
+		actions = append(actions, importCodeAction(params.TextDocument.URI, f.AST.Forms, imp.Import, diag))
+	}
+
 	slog.InfoContext(ctx, "code action request",
 		"uri", params.TextDocument.URI, "range", params.Range, "actions", len(actions))
 
@@ -110,12 +110,12 @@ func (h *langHandler) getLexicalCompletions(ctx context.Context, root dang.Node,
 				documentation = doc
 			}
 
-			items = append(items, CompletionItem{
+			items = append(items, withSnippet(CompletionItem{
 				Label:         name,
 				Kind:          kind,
 				Detail:        memberType.String(),
 				Documentation: documentation,
-			})
+			}, dang.CallSnippet(name, memberType)))
 		}
 	}
 
@@ -133,12 +133,22 @@ func completionsToItems(completions []dang.Completion) []CompletionItem {
 		if c.IsArg {
 			kind = FieldCompletion
 		}
-		items[i] = CompletionItem{
+		items[i] = withSnippet(CompletionItem{
 			Label:         c.Label,
 			Kind:          kind,
 			Detail:        c.Detail,
 			Documentation: c.Documentation,
-		}
+		}, c.Snippet)
 	}
 	return items
 }
+
+// withSnippet makes the item insert a call template with tab stops for the
+// required arguments, when there are any.
+func withSnippet(item CompletionItem, snippet string) CompletionItem {
+	if snippet != "" {
+		item.InsertText = snippet
+		item.InsertTextFormat = SnippetTextFormat
+	}
+	return item
+}
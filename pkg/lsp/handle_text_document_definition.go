@@ -5,6 +5,8 @@ import (
 	"strings"
 
 	"github.com/creachadair/jrpc2"
+	"github.com/vito/dang/v2/pkg/dang"
+	"github.com/vito/dang/v2/pkg/hm"
 )
 
 func (h *langHandler) handleTextDocumentDefinition(ctx context.Context, req *jrpc2.Request) (any, error) {
@@ -28,23 +30,251 @@ func (h *langHandler) handleTextDocumentDefinition(ctx context.Context, req *jrp
 		return nil, nil
 	}
 
-	// Look up the definition
-	if def, ok := f.Symbols.Definitions[symbolName]; ok {
-		return def.Location, nil
+	if loc := h.resolveDefinition(params.TextDocument.URI, f, params.Position, symbolName); loc != nil {
+		return loc, nil
 	}
 
 	return nil, nil
 }
 
+// resolveDefinition finds the declaration that the identifier name at pos
+// refers to, if it is declared in this module.
+func (h *langHandler) resolveDefinition(uri DocumentURI, f *File, pos Position, name string) *Location {
+	target, ok := h.targetAtPosition(uri, f, pos, name)
+	if !ok || !target.owned() {
+		return nil
+	}
+	return &target.Declaration
+}
+
+// referenceTarget identifies what an identifier resolves to. Anything
+// declared in the module is identified by where it is declared. Names the
+// module doesn't own (schema types and their fields, builtins) have no
+// declaration to point at, so they are identified by name instead, and
+// members additionally by the name of their receiver's type.
+type referenceTarget struct {
+	Declaration Location
+	Owner       string
+	Name        string
+}
+
+// owned reports whether the target is declared in the module.
+func (t referenceTarget) owned() bool {
+	return t.Declaration.URI != ""
+}
+
+// targetAtPosition resolves the identifier name at pos. A selected member
+// resolves through its receiver's type, a local through the innermost
+// enclosing scope that binds it, and anything else through the module-wide
+// symbol table, which spans every file in the module directory.
+func (h *langHandler) targetAtPosition(uri DocumentURI, f *File, pos Position, name string) (referenceTarget, bool) {
+	if f.AST != nil {
+		if sel, ok := h.findNodeAtPosition(f.AST, pos).(*dang.Select); ok &&
+			sel.Field != nil && sel.Field.Name == name && !positionWithinNode(sel.Receiver, pos) {
+			return h.selectTarget(f, sel)
+		}
+	}
+	return h.nameTarget(uri, f, pos, name), true
+}
+
+// selectTarget resolves a member selection through its receiver's type. A
+// member that resolves to nothing we declared (e.g. a schema field) must not
+// fall back to an unrelated declaration that happens to share the name.
+func (h *langHandler) selectTarget(f *File, sel *dang.Select) (referenceTarget, bool) {
+	name := sel.Field.Name
+	t := sel.Receiver.GetInferredType()
+	if loc := h.memberDefinition(f, t, name); loc != nil {
+		return referenceTarget{Declaration: *loc, Name: name}, true
+	}
+	mod := namedType(t)
+	if mod == nil {
+		return referenceTarget{}, false
+	}
+	return referenceTarget{Owner: mod.Name(), Name: name}, true
+}
+
+// nameTarget resolves a bare name at pos.
+func (h *langHandler) nameTarget(uri DocumentURI, f *File, pos Position, name string) referenceTarget {
+	if f.AST != nil {
+		if decl := localDeclaration(f.AST, pos, name); decl != nil {
+			if loc := symbolLocation(uri, decl.Name); loc != nil {
+				return referenceTarget{Declaration: *loc, Name: name}
+			}
+		}
+	}
+	return moduleTarget(f, name)
+}
+
+// moduleTarget resolves name against the module-wide symbol table.
+func moduleTarget(f *File, name string) referenceTarget {
+	if f.Symbols != nil {
+		if def, ok := f.Symbols.Definitions[name]; ok && def.Location != nil {
+			return referenceTarget{Declaration: *def.Location, Name: name}
+		}
+	}
+	return referenceTarget{Name: name}
+}
+
+// memberDefinition finds the declaration of member name on a receiver of
+// type t, when that type is declared in this module (possibly in a sibling
+// file).
+func (h *langHandler) memberDefinition(f *File, t hm.Type, name string) *Location {
+	mod := namedType(t)
+	if mod == nil || f.Symbols == nil {
+		return nil
+	}
+	def, ok := f.Symbols.Definitions[mod.Name()]
+	if !ok {
+		return nil
+	}
+	var members []*dang.Symbol
+	switch decl := def.Node.(type) {
+	case *dang.ObjectDecl:
+		members = memberNames(decl.Value)
+	case *dang.InterfaceDecl:
+		members = memberNames(decl.Value)
+	case *dang.ScalarDecl:
+		members = memberNames(decl.Value)
+	case *dang.EnumDecl:
+		members = decl.Values
+	}
+	for _, member := range members {
+		if member != nil && member.Name == name {
+			return symbolLocation(def.Location.URI, member)
+		}
+	}
+	return nil
+}
+
+// namedType returns the module type behind t, if it is one.
+func namedType(t hm.Type) *dang.Type {
+	if nn, ok := t.(hm.NonNullType); ok {
+		t = nn.Type
+	}
+	mod, _ := t.(*dang.Type)
+	return mod
+}
+
+// memberNames returns the names of the fields declared in a type body.
+func memberNames(body *dang.Block) []*dang.Symbol {
+	if body == nil {
+		return nil
+	}
+	return fieldNames(fieldDecls(body.Forms))
+}
+
+// localDeclaration returns the innermost declaration of name visible at pos:
+// a function or block argument, or a field/let in an enclosing block. Within
+// one block the last declaration before pos wins, so a rebinding shadows the
+// one above it. A rebinding's own value still reads the binding it shadows,
+// as in `let n = n + 1`; only a function can refer to itself.
+//
+// Files and type bodies allow forward references, so a declaration after pos
+// still counts there when none comes before it. In a function body a `let`
+// only binds from where it's declared, so a later one never does.
+func localDeclaration(root dang.Node, pos Position, name string) *dang.FieldDecl {
+	var found *dang.FieldDecl
+	typeBodies := map[*dang.Block]bool{}
+	root.Walk(func(n dang.Node) bool {
+		if n == nil {
+			return false
+		}
+		switch typed := n.(type) {
+		case *dang.ObjectDecl:
+			typeBodies[typed.Value] = true
+		case *dang.InterfaceDecl:
+			typeBodies[typed.Value] = true
+		case *dang.ScalarDecl:
+			typeBodies[typed.Value] = true
+		}
+		if !positionWithinNode(n, pos) {
+			return true
+		}
+		var candidates []*dang.FieldDecl
+		sequential := false
+		switch typed := n.(type) {
+		case *dang.FunDecl:
+			candidates = append(candidates, typed.Args...)
+			if typed.BlockParam != nil {
+				candidates = append(candidates, typed.BlockParam)
+			}
+		case *dang.BlockArg:
+			candidates = typed.Args
+		case *dang.Block:
+			candidates = fieldDecls(typed.Forms)
+			sequential = !typeBodies[typed]
+		case *dang.FileBlock:
+			candidates = fieldDecls(typed.Forms)
+		}
+		var match *dang.FieldDecl
+		for _, decl := range candidates {
+			if decl == nil || decl.Name == nil || decl.Name.Name != name || decl.Name.Loc == nil {
+				continue
+			}
+			if _, isFn := decl.Value.(*dang.FunDecl); !isFn && positionWithinNode(decl.Value, pos) {
+				continue
+			}
+			declPos := Position{Line: decl.Name.Loc.Line - 1, Character: decl.Name.Loc.Column - 1}
+			if sequential && positionAfter(declPos, pos) {
+				continue
+			}
+			if match == nil || !positionAfter(declPos, pos) {
+				match = decl
+			}
+		}
+		if match != nil {
+			found = match
+		}
+		return true
+	})
+	return found
+}
+
+func fieldDecls(forms []dang.Node) []*dang.FieldDecl {
+	var decls []*dang.FieldDecl
+	for _, form := range forms {
+		if decl, ok := form.(*dang.FieldDecl); ok {
+			decls = append(decls, decl)
+		}
+	}
+	return decls
+}
+
+// symbolLocation returns the location of exactly the declared name.
+func symbolLocation(uri DocumentURI, sym *dang.Symbol) *Location {
+	loc := sym.Loc
+	if loc == nil {
+		return nil
+	}
+	return &Location{
+		URI: uri,
+		Range: Range{
+			Start: Position{Line: loc.Line - 1, Character: loc.Column - 1},
+			End:   Position{Line: loc.Line - 1, Character: loc.Column - 1 + len(sym.Name)},
+		},
+	}
+}
+
 func (h *langHandler) symbolAtPosition(f *File, pos Position) string {
-	lines := strings.Split(f.Text, "\n")
-	if pos.Line >= len(lines) {
+	r, ok := identifierRangeAtPosition(f.Text, pos)
+	if !ok {
 		return ""
 	}
+	line := strings.Split(f.Text, "\n")[pos.Line]
+	return line[r.Start.Character:r.End.Character]
+}
+
+// identifierRangeAtPosition returns the span of the identifier under the
+// cursor, if there is one.
+func identifierRangeAtPosition(text string, pos Position) (Range, bool) {
+	lines := strings.Split(text, "\n")
+	if pos.Line >= len(lines) {
+		return Range{}, false
+	}
 
 	line := lines[pos.Line]
 	if pos.Character >= len(line) {
-		return ""
+		return Range{}, false
 	}
 
 	// Find word boundaries around the cursor
@@ -59,8 +289,11 @@ func (h *langHandler) symbolAtPosition(f *File, pos Position) string {
 	}
 
 	if start == end {
-		return ""
+		return Range{}, false
 	}
 
-	return line[start:end]
+	return Range{
+		Start: Position{Line: pos.Line, Character: start},
+		End:   Position{Line: pos.Line, Character: end},
+	}, true
 }
@@ -0,0 +1,129 @@
+package lsp
+
+import (
+	"context"
+
+	"github.com/creachadair/jrpc2"
+	"github.com/vito/dang/v2/pkg/dang"
+)
+
+func (h *langHandler) handleTextDocumentDocumentSymbol(ctx context.Context, req *jrpc2.Request) (any, error) {
+	if !req.HasParams() {
+		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "missing parameters")
+	}
+
+	var params DocumentSymbolParams
+	if err := req.UnmarshalParams(&params); err != nil {
+		return nil, err
+	}
+
+	f := h.waitForFile(params.TextDocument.URI)
+	if f == nil || f.AST == nil {
+		return []DocumentSymbol{}, nil
+	}
+
+	return documentSymbols(f.AST.Forms), nil
+}
+
+// documentSymbols builds the outline of a file: each type with its members
+// nested underneath, alongside the top-level bindings.
+func documentSymbols(forms []dang.Node) []DocumentSymbol {
+	symbols := []DocumentSymbol{}
+	for _, form := range forms {
+		var sym *DocumentSymbol
+		switch n := form.(type) {
+		case *dang.ObjectDecl:
+			sym = declSymbol(n, n.Name, ClassSymbol, "")
+			if sym != nil {
+				sym.Children = memberSymbols(n.Value)
+			}
+		case *dang.InterfaceDecl:
+			sym = declSymbol(n, n.Name, InterfaceSymbol, "")
+			if sym != nil {
+				sym.Children = memberSymbols(n.Value)
+			}
+		case *dang.ScalarDecl:
+			sym = declSymbol(n, n.Name, ClassSymbol, "")
+			if sym != nil {
+				sym.Children = memberSymbols(n.Value)
+			}
+		case *dang.UnionDecl:
+			sym = declSymbol(n, n.Name, ClassSymbol, "")
+		case *dang.EnumDecl:
+			sym = declSymbol(n, n.Name, EnumSymbol, "")
+			if sym != nil {
+				for _, value := range n.Values {
+					if r, ok := symbolRange(value); ok {
+						sym.Children = append(sym.Children, DocumentSymbol{
+							Name:           value.Name,
+							Kind:           EnumMemberSymbol,
+							Range:          r,
+							SelectionRange: r,
+						})
+					}
+				}
+			}
+		case *dang.FieldDecl:
+			sym = fieldSymbol(n, ConstantSymbol)
+		}
+		if sym != nil {
+			symbols = append(symbols, *sym)
+		}
+	}
+	return symbols
+}
+
+// memberSymbols lists the fields and methods declared in a type body.
+func memberSymbols(body *dang.Block) []DocumentSymbol {
+	if body == nil {
+		return nil
+	}
+	var members []DocumentSymbol
+	for _, form := range body.Forms {
+		if field, ok := form.(*dang.FieldDecl); ok {
+			if sym := fieldSymbol(field, FieldSymbol); sym != nil {
+				members = append(members, *sym)
+			}
+		}
+	}
+	return members
+}
+
+// fieldSymbol describes a field declaration: a method if it's a function,
+// detailed with its parameter signature, or else a binding of the given kind.
+func fieldSymbol(field *dang.FieldDecl, kind SymbolKind) *DocumentSymbol {
+	var detail string
+	if _, isFn := field.Value.(*dang.FunDecl); isFn {
+		kind = MethodSymbol
+		if t := field.Value.GetInferredType(); t != nil {
+			detail = t.String()
+		}
+	} else if t := field.GetInferredType(); t != nil {
+		detail = t.String()
+	}
+	return declSymbol(field, field.Name, kind, detail)
+}
+
+// declSymbol describes a declaration whose full range is the node's span and
+// whose selection range is just its name.
+func declSymbol(node dang.Node, name *dang.Symbol, kind SymbolKind, detail string) *DocumentSymbol {
+	if name == nil {
+		return nil
+	}
+	selection, ok := symbolRange(name)
+	if !ok {
+		return nil
+	}
+	full, ok := nodeRange(node)
+	// Clients reject a selection range that falls outside the full range.
+	if !ok || positionAfter(full.Start, selection.Start) || positionAfter(selection.End, full.End) {
+		full = selection
+	}
+	return &DocumentSymbol{
+		Name:           name.Name,
+		Detail:         detail,
+		Kind:           kind,
+		Range:          full,
+		SelectionRange: selection,
+	}
+}
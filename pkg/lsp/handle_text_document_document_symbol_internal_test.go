@@ -0,0 +1,54 @@
+package lsp
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestDocumentSymbols(t *testing.T) {
+	h, uris := openModule(t, dogModule)
+
+	dog := h.waitForFile(uris["dog.dang"])
+	if dog == nil || dog.AST == nil {
+		t.Fatal("dog.dang was not analyzed")
+	}
+	symbols := documentSymbols(dog.AST.Forms)
+	if len(symbols) != 1 || symbols[0].Name != "Dog" || symbols[0].Kind != ClassSymbol {
+		t.Fatalf("dog.dang outline = %+v, want just the Dog type", symbols)
+	}
+	typ := symbols[0]
+	if typ.SelectionRange != (Range{Start: Position{Line: 0, Character: 5}, End: Position{Line: 0, Character: 8}}) {
+		t.Errorf("Dog selection range = %+v", typ.SelectionRange)
+	}
+	if typ.Range.Start.Line != 0 || typ.Range.End.Line != 3 {
+		t.Errorf("Dog range = %+v, want lines 0-3", typ.Range)
+	}
+	if len(typ.Children) != 2 {
+		t.Fatalf("Dog members = %+v, want name and sound", typ.Children)
+	}
+	if name := typ.Children[0]; name.Name != "name" || name.Kind != FieldSymbol || name.Detail != "String!" {
+		t.Errorf("name member = %+v", name)
+	}
+	if sound := typ.Children[1]; sound.Name != "sound" || sound.Kind != MethodSymbol {
+		t.Errorf("sound member = %+v", sound)
+	}
+
+	mainFile := h.waitForFile(uris["main.dang"])
+	if mainFile == nil || mainFile.AST == nil {
+		t.Fatal("main.dang was not analyzed")
+	}
+	kinds := map[string]DocumentSymbol{}
+	for _, sym := range documentSymbols(mainFile.AST.Forms) {
+		kinds[sym.Name] = sym
+	}
+	if kinds["rex"].Kind != ConstantSymbol {
+		t.Errorf("rex = %+v, want a constant", kinds["rex"])
+	}
+	label := kinds["label"]
+	if label.Kind != MethodSymbol || !strings.Contains(label.Detail, "d: Dog!") {
+		t.Errorf("label = %+v, want a method showing its parameters", label)
+	}
+	if strings.Contains(label.Detail, "label") {
+		t.Errorf("label detail should not repeat the name: %q", label.Detail)
+	}
+}
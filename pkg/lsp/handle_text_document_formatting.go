@@ -2,8 +2,10 @@ package lsp
 
 import (
 	"context"
+	"strings"
 
 	"github.com/creachadair/jrpc2"
+	"github.com/pmezard/go-difflib/difflib"
 	"github.com/vito/dang/v2/pkg/dang"
 )
 
@@ -36,26 +38,119 @@ func (h *langHandler) handleTextDocumentFormatting(ctx context.Context, req *jrp
 		return []TextEdit{}, nil
 	}
 
-	// Count lines in original text to get the full range
-	lines := 0
-	lastLineLen := 0
-	for i, c := range f.Text {
-		if c == '\n' {
-			lines++
-			lastLineLen = 0
-		} else {
-			lastLineLen = len(f.Text) - i
-		}
-	}
-
 	// Return a single edit that replaces the entire document
 	return []TextEdit{
 		{
 			Range: Range{
 				Start: Position{Line: 0, Character: 0},
-				End:   Position{Line: lines, Character: lastLineLen},
+				End:   documentEnd(f.Text),
 			},
 			NewText: formatted,
 		},
 	}, nil
 }
+
+func (h *langHandler) handleTextDocumentRangeFormatting(ctx context.Context, req *jrpc2.Request) (any, error) {
+	if !req.HasParams() {
+		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "missing parameters")
+	}
+
+	var params DocumentRangeFormattingParams
+	if err := req.UnmarshalParams(&params); err != nil {
+		return nil, err
+	}
+
+	f := h.waitForFile(params.TextDocument.URI)
+	if f == nil {
+		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "document not found: %v", params.TextDocument.URI)
+	}
+
+	// The whole buffer has to parse for the formatter to run at all; on a
+	// syntax error leave the document alone, same as full formatting.
+	formatted, err := dang.FormatFile([]byte(f.Text))
+	if err != nil {
+		return []TextEdit{}, nil
+	}
+
+	return rangeFormattingEdits(f.Text, formatted, params.Range), nil
+}
+
+// rangeFormattingEdits returns the edits that bring the lines touched by r
+// into canonical form. It diffs the original text against the fully
+// formatted text line by line and keeps only the hunks that overlap the
+// selected lines, so code outside the selection is never rewritten.
+func rangeFormattingEdits(text, formatted string, r Range) []TextEdit {
+	a := splitLines(text)
+	b := splitLines(formatted)
+
+	startLine, endLine := r.Start.Line, r.End.Line
+	// A selection ending at column 0 doesn't include that line.
+	if endLine > startLine && r.End.Character == 0 {
+		endLine--
+	}
+
+	edits := []TextEdit{}
+	for _, op := range lineOpCodes(a, b) {
+		// Lines [I1, I2) of the original are replaced; an insertion (I1 ==
+		// I2) sits between lines and overlaps if it lands inside the range.
+		if op.I1 > endLine || max(op.I2-1, op.I1) < startLine {
+			continue
+		}
+
+		end := Position{Line: op.I2, Character: 0}
+		if op.I2 == len(a) {
+			end = documentEnd(text)
+		}
+		edits = append(edits, TextEdit{
+			Range: Range{
+				Start: Position{Line: op.I1, Character: 0},
+				End:   end,
+			},
+			NewText: strings.Join(b[op.J1:op.J2], ""),
+		})
+	}
+	return edits
+}
+
+// lineOpCodes returns the non-equal diff hunks between a and b. A hunk
+// that replaces lines one-for-one (the common reindent case) is split into
+// single-line hunks, so that a selection covering part of it only picks up
+// its own lines.
+func lineOpCodes(a, b []string) []difflib.OpCode {
+	var ops []difflib.OpCode
+	for _, op := range difflib.NewMatcher(a, b).GetOpCodes() {
+		switch {
+		case op.Tag == 'e':
+			continue
+		case op.Tag == 'r' && op.I2-op.I1 == op.J2-op.J1:
+			for k := 0; k < op.I2-op.I1; k++ {
+				ops = append(ops, difflib.OpCode{
+					Tag: 'r',
+					I1:  op.I1 + k,
+					I2:  op.I1 + k + 1,
+					J1:  op.J1 + k,
+					J2:  op.J1 + k + 1,
+				})
+			}
+		default:
+			ops = append(ops, op)
+		}
+	}
+	return ops
+}
+
+// splitLines splits text into lines, keeping each line's trailing newline.
+func splitLines(text string) []string {
+	lines := strings.SplitAfter(text, "\n")
+	if lines[len(lines)-1] == "" {
+		lines = lines[:len(lines)-1]
+	}
+	return lines
+}
+
+// documentEnd returns the position just past the last character of text.
+func documentEnd(text string) Position {
+	line := strings.Count(text, "\n")
+	lastLine := text[strings.LastIndexByte(text, '\n')+1:]
+	return Position{Line: line, Character: len(lastLine)}
+}
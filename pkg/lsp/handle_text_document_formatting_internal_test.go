@@ -0,0 +1,65 @@
+package lsp
+
+import (
+	"testing"
+)
+
+func TestRangeFormattingEditsOnlyTouchSelection(t *testing.T) {
+	text := "let  a = 1\nlet b = 2\nlet   c = 3\n"
+	formatted := "let a = 1\nlet b = 2\nlet c = 3\n"
+
+	// Select only the first line: the third line stays as written.
+	edits := rangeFormattingEdits(text, formatted, Range{
+		Start: Position{Line: 0, Character: 0},
+		End:   Position{Line: 0, Character: 10},
+	})
+	if len(edits) != 1 {
+		t.Fatalf("expected 1 edit, got %d: %+v", len(edits), edits)
+	}
+	edit := edits[0]
+	if edit.Range.Start != (Position{Line: 0}) || edit.Range.End != (Position{Line: 1}) {
+		t.Errorf("unexpected edit range: %+v", edit.Range)
+	}
+	if edit.NewText != "let a = 1\n" {
+		t.Errorf("unexpected edit text: %q", edit.NewText)
+	}
+}
+
+func TestRangeFormattingEditsExcludeLineEndingSelection(t *testing.T) {
+	text := "let  a = 1\nlet   b = 2\n"
+	formatted := "let a = 1\nlet b = 2\n"
+
+	// A selection ending at column 0 of line 1 covers line 0 only.
+	edits := rangeFormattingEdits(text, formatted, Range{
+		Start: Position{Line: 0, Character: 0},
+		End:   Position{Line: 1, Character: 0},
+	})
+	if len(edits) != 1 || edits[0].NewText != "let a = 1\n" {
+		t.Fatalf("expected a single edit for line 0, got %+v", edits)
+	}
+}
+
+func TestRangeFormattingEditsNoChanges(t *testing.T) {
+	text := "let a = 1\n"
+	edits := rangeFormattingEdits(text, text, Range{
+		Start: Position{Line: 0, Character: 0},
+		End:   Position{Line: 0, Character: 9},
+	})
+	if len(edits) != 0 {
+		t.Fatalf("expected no edits, got %+v", edits)
+	}
+}
+
+func TestDocumentEnd(t *testing.T) {
+	for text, want := range map[string]Position{
+		"":            {Line: 0, Character: 0},
+		"abc":         {Line: 0, Character: 3},
+		"abc\n":       {Line: 1, Character: 0},
+		"abc\nde":     {Line: 1, Character: 2},
+		"abc\nde\n\n": {Line: 3, Character: 0},
+	} {
+		if got := documentEnd(text); got != want {
+			t.Errorf("documentEnd(%q) = %+v, want %+v", text, got, want)
+		}
+	}
+}
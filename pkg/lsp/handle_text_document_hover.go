@@ -31,6 +31,20 @@ func (h *langHandler) handleTextDocumentHover(ctx context.Context, req *jrpc2.Re
 		return nil, nil
 	}
 
+	result, err := h.hover(ctx, f, params)
+	if err != nil {
+		return nil, err
+	}
+	// Anchor the popup on exactly the identifier under the cursor.
+	if hover, ok := result.(*Hover); ok && hover != nil && hover.Range == nil {
+		if r, ok := identifierRangeAtPosition(f.Text, params.Position); ok {
+			hover.Range = &r
+		}
+	}
+	return result, nil
+}
+
+func (h *langHandler) hover(ctx context.Context, f *File, params HoverParams) (any, error) {
 	// Find the node at this position to get its inferred type
 	node := h.findNodeAtPosition(f.AST, params.Position)
 
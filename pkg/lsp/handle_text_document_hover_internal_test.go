@@ -219,3 +219,25 @@ func TestHoverResultWithDocBelow(t *testing.T) {
 		t.Fatalf("hover markdown:\n%s\nwant:\n%s", contents.Value, want)
 	}
 }
+
+func TestIdentifierRangeAtPosition(t *testing.T) {
+	text := "let base = container.from(\"alpine\")\n"
+
+	// Cursor in the middle of `from` spans exactly that identifier.
+	r, ok := identifierRangeAtPosition(text, Position{Line: 0, Character: 23})
+	if !ok {
+		t.Fatalf("expected an identifier at the cursor")
+	}
+	want := Range{
+		Start: Position{Line: 0, Character: 21},
+		End:   Position{Line: 0, Character: 25},
+	}
+	if r != want {
+		t.Fatalf("range = %+v, want %+v", r, want)
+	}
+
+	// Cursor on punctuation has no identifier.
+	if _, ok := identifierRangeAtPosition(text, Position{Line: 0, Character: 9}); ok {
+		t.Fatalf("expected no identifier on `=`")
+	}
+}
@@ -0,0 +1,148 @@
+package lsp
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/creachadair/jrpc2"
+	"github.com/vito/dang/v2/pkg/dang"
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+func (h *langHandler) handleTextDocumentInlayHint(ctx context.Context, req *jrpc2.Request) (any, error) {
+	if !req.HasParams() {
+		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "missing parameters")
+	}
+
+	var params InlayHintParams
+	if err := req.UnmarshalParams(&params); err != nil {
+		return nil, err
+	}
+
+	f := h.waitForFile(params.TextDocument.URI)
+	if f == nil || f.AST == nil {
+		return nil, nil
+	}
+
+	hints := []InlayHint{}
+	for _, hint := range typeHints(f.AST, params.Range) {
+		hints = append(hints, InlayHint{
+			Position: hint.Position,
+			Label:    ": " + hint.Type.String(),
+			Kind:     TypeInlayHint,
+			Data: inlayHintData{
+				URI:      params.TextDocument.URI,
+				Position: hint.Position,
+			},
+		})
+	}
+	return hints, nil
+}
+
+// handleInlayHintResolve fills in a hint's tooltip with the shape of the
+// hinted type. Rendering a type's members is far more work than naming it, so
+// it's left until the client actually shows a hint.
+func (h *langHandler) handleInlayHintResolve(ctx context.Context, req *jrpc2.Request) (any, error) {
+	if !req.HasParams() {
+		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "missing parameters")
+	}
+
+	var hint InlayHint
+	if err := req.UnmarshalParams(&hint); err != nil {
+		return nil, err
+	}
+	var payload struct {
+		Data inlayHintData `json:"data"`
+	}
+	if err := req.UnmarshalParams(&payload); err != nil {
+		return nil, err
+	}
+
+	f := h.waitForFile(payload.Data.URI)
+	if f == nil || f.AST == nil {
+		return hint, nil
+	}
+
+	pos := payload.Data.Position
+	for _, candidate := range typeHints(f.AST, Range{Start: pos, End: pos}) {
+		if candidate.Position != pos {
+			continue
+		}
+		codeBlock, docString := formatModuleForHover(namedType(candidate.Type), "")
+		if codeBlock == "" {
+			break
+		}
+		content := fmt.Sprintf("```dang\n%s\n```", codeBlock)
+		if docString != "" {
+			content = fmt.Sprintf("%s\n\n---\n\n%s", content, docString)
+		}
+		hint.Tooltip = &MarkupContent{
+			Kind:  Markdown,
+			Value: content,
+		}
+		break
+	}
+	return hint, nil
+}
+
+// inlayHintData is round-tripped through the client so a resolve request can
+// find its hint again.
+type inlayHintData struct {
+	URI      DocumentURI `json:"uri"`
+	Position Position    `json:"position"`
+}
+
+// typeHint is an inferred type to show after a binding that wasn't annotated.
+type typeHint struct {
+	Position Position
+	Type     hm.Type
+}
+
+// typeHints returns a hint for every unannotated binding within rng whose
+// type inference settled: fields and lets, function arguments with only a
+// default, and block parameters. Function declarations are skipped since
+// their signature is already spelled out.
+func typeHints(root dang.Node, rng Range) []typeHint {
+	var hints []typeHint
+	add := func(sym *dang.Symbol, t hm.Type) {
+		if sym == nil || sym.Loc == nil || t == nil || len(t.FreeTypeVar()) > 0 {
+			return
+		}
+		pos := Position{Line: sym.Loc.Line - 1, Character: sym.Loc.Column - 1 + len(sym.Name)}
+		if !rangesOverlap(Range{Start: pos, End: pos}, rng) {
+			return
+		}
+		hints = append(hints, typeHint{Position: pos, Type: t})
+	}
+
+	root.Walk(func(n dang.Node) bool {
+		if n == nil {
+			return false
+		}
+		switch node := n.(type) {
+		case *dang.FieldDecl:
+			if node.Type_ != nil {
+				break
+			}
+			if _, ok := node.Value.(*dang.FunDecl); ok {
+				break
+			}
+			add(node.Name, node.GetInferredType())
+		case *dang.BlockArg:
+			if node.InferredScope == nil {
+				break
+			}
+			for _, arg := range node.Args {
+				if arg.Type_ != nil || arg.Name == nil {
+					continue
+				}
+				if scheme, ok := node.InferredScope.LocalSchemeOf(arg.Name.Name); ok {
+					t, _ := scheme.Type()
+					add(arg.Name, t)
+				}
+			}
+		}
+		return true
+	})
+	return hints
+}
@@ -0,0 +1,55 @@
+package lsp
+
+import (
+	"context"
+	"testing"
+
+	"github.com/vito/dang/v2/pkg/dang"
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+func TestTypeHintsSkipAnnotatedBindings(t *testing.T) {
+	text := `type Dog {
+  pub name = "Rex"
+  pub age: Int! = 3
+}
+let dog = Dog
+let odds: [Int!]! = [1, 3]
+let labels = odds.map { n => n.toString }
+`
+	parsed, err := dang.Parse("test.dang", []byte(text))
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+	mod := parsed.(*dang.FileBlock)
+	if _, err := mod.Infer(context.Background(), dang.NewPreludeTypeScope(""), hm.NewSimpleFresher()); err != nil {
+		t.Fatalf("infer: %v", err)
+	}
+
+	everything := Range{End: Position{Line: 100}}
+	got := map[Position]string{}
+	for _, hint := range typeHints(mod, everything) {
+		got[hint.Position] = hint.Type.String()
+	}
+
+	want := map[Position]string{
+		{Line: 1, Character: 10}: "String!",
+		{Line: 4, Character: 7}:  "Dog!",
+		{Line: 6, Character: 10}: "[String!]!",
+		{Line: 6, Character: 25}: "Int!",
+	}
+	if len(got) != len(want) {
+		t.Errorf("hints = %v, want %v", got, want)
+	}
+	for pos, typ := range want {
+		if got[pos] != typ {
+			t.Errorf("hint at %d:%d = %q, want %q", pos.Line, pos.Character, got[pos], typ)
+		}
+	}
+
+	// Only the requested range is hinted.
+	inRange := typeHints(mod, Range{Start: Position{Line: 4}, End: Position{Line: 4, Character: 20}})
+	if len(inRange) != 1 || inRange[0].Position != (Position{Line: 4, Character: 7}) {
+		t.Errorf("hints in range = %+v", inRange)
+	}
+}
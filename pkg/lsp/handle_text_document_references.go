@@ -0,0 +1,305 @@
+package lsp
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/creachadair/jrpc2"
+	"github.com/vito/dang/v2/pkg/dang"
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+func (h *langHandler) handleTextDocumentReferences(ctx context.Context, req *jrpc2.Request) (any, error) {
+	if !req.HasParams() {
+		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "missing parameters")
+	}
+
+	var params ReferenceParams
+	if err := req.UnmarshalParams(&params); err != nil {
+		return nil, err
+	}
+
+	f := h.waitForFile(params.TextDocument.URI)
+	if f == nil {
+		return nil, nil
+	}
+
+	name := h.symbolAtPosition(f, params.Position)
+	if name == "" {
+		return nil, nil
+	}
+
+	refs, err := h.findReferences(ctx, params.TextDocument.URI, params.Position, name)
+	if err != nil || refs == nil {
+		return nil, err
+	}
+
+	locations := []Location{}
+	for _, ref := range refs.References {
+		if ref.Declaration && !params.Context.IncludeDeclaration {
+			continue
+		}
+		locations = append(locations, ref.Location)
+	}
+	return locations, nil
+}
+
+// reference is a single use site of a referenceTarget.
+type reference struct {
+	Location    Location
+	Declaration bool
+}
+
+// moduleFile is one fully inferred file of a module, with the symbol table
+// that file sees: its own declarations first, then its siblings'.
+type moduleFile struct {
+	URI  DocumentURI
+	File *File
+}
+
+// referenceSet holds every use site of one target across a module.
+type referenceSet struct {
+	Target     referenceTarget
+	References []reference
+	Files      []moduleFile
+}
+
+// findReferences resolves the identifier name at pos and collects every use
+// site of whatever it resolves to, across every file in the module
+// directory. It returns nil if the file at uri doesn't parse.
+func (h *langHandler) findReferences(ctx context.Context, uri DocumentURI, pos Position, name string) (*referenceSet, error) {
+	fp, err := fromURI(uri)
+	if err != nil {
+		return nil, err
+	}
+
+	files, err := h.resolveModule(ctx, uri, fp)
+	if err != nil {
+		return nil, err
+	}
+
+	var target referenceTarget
+	var found bool
+	for _, file := range files {
+		if file.URI == uri {
+			target, found = h.targetAtPosition(file.URI, file.File, pos, name)
+			break
+		}
+	}
+	if !found {
+		return nil, nil
+	}
+
+	refs := &referenceSet{Target: target, Files: files}
+	seen := map[Location]bool{}
+	for _, file := range files {
+		for _, ref := range h.fileReferences(file, target) {
+			// Signatures are reachable both through the declaration and its
+			// function type, so a site can turn up twice.
+			if seen[ref.Location] {
+				continue
+			}
+			seen[ref.Location] = true
+			refs.References = append(refs.References, ref)
+		}
+	}
+	return refs, nil
+}
+
+// resolveModule parses and fully infers every file in the directory of fp.
+// Buffer analysis only infers sibling declarations, leaving member selections
+// in their bodies untyped, but references have to resolve every use site.
+// The files are parsed afresh rather than taken from the parse cache, so this
+// inference doesn't touch the blocks buffer analysis works on.
+func (h *langHandler) resolveModule(ctx context.Context, uri DocumentURI, fp string) ([]moduleFile, error) {
+	fileDir := filepath.Dir(fp)
+	paths, err := h.directoryDangFiles(fileDir)
+	if err != nil {
+		return nil, err
+	}
+
+	var files []directoryFile
+	var blocks []*dang.FileBlock
+	for _, path := range paths {
+		text, err := h.readDangFile(path)
+		if err != nil {
+			return nil, err
+		}
+		block, err := dang.ParseRecovering(path, []byte(text), dang.GlobalStore("filePath", path))
+		if err != nil {
+			// Nothing in a file that doesn't parse can be resolved.
+			continue
+		}
+		fileURI := toURI(path)
+		if sameFile(path, fp) {
+			fileURI = uri
+		}
+		files = append(files, directoryFile{URI: fileURI, Block: block})
+		blocks = append(blocks, block)
+	}
+
+	ctx, _ = h.directoryInferenceContext(ctx, fileDir)
+	ctx, _ = dang.WithInferWarningSink(ctx)
+	// Type errors leave only the offending nodes untyped; everything else
+	// still resolves, so they aren't fatal here. They're already reported as
+	// diagnostics.
+	_ = dang.InferDirectoryFiles(ctx, blocks, dang.NewPreludeTypeScope(""), hm.NewSimpleFresher())
+
+	modFiles := make([]moduleFile, 0, len(files))
+	for _, file := range files {
+		modFiles = append(modFiles, moduleFile{
+			URI: file.URI,
+			File: &File{
+				AST:     file.Block,
+				Symbols: h.buildDirectorySymbolTable(files, file.URI),
+			},
+		})
+	}
+	return modFiles, nil
+}
+
+// readDangFile returns the contents of path, preferring an open buffer's
+// unsaved text over what's on disk.
+func (h *langHandler) readDangFile(path string) (string, error) {
+	h.mu.Lock()
+	openFile := h.files[toURI(path)]
+	h.mu.Unlock()
+
+	if openFile != nil {
+		openFile.mu.Lock()
+		defer openFile.mu.Unlock()
+		return openFile.Text, nil
+	}
+
+	contents, err := os.ReadFile(path)
+	if err != nil {
+		return "", fmt.Errorf("read %s: %w", path, err)
+	}
+	return string(contents), nil
+}
+
+// fileReferences collects the use sites of target in one file: bare names,
+// member selections, type annotations, and declarations.
+func (h *langHandler) fileReferences(file moduleFile, target referenceTarget) []reference {
+	var refs []reference
+	name := target.Name
+
+	add := func(sym *dang.Symbol, resolved referenceTarget) {
+		if resolved != target {
+			return
+		}
+		if loc := symbolLocation(file.URI, sym); loc != nil {
+			refs = append(refs, reference{
+				Location:    *loc,
+				Declaration: *loc == target.Declaration,
+			})
+		}
+	}
+	// A declaration resolves to itself.
+	declared := func(sym *dang.Symbol) {
+		if sym == nil || sym.Name != name {
+			return
+		}
+		if loc := symbolLocation(file.URI, sym); loc != nil {
+			add(sym, referenceTarget{Declaration: *loc, Name: name})
+		}
+	}
+	// A type name resolves through the module-wide symbol table; there are
+	// no local types.
+	typeName := func(sym *dang.Symbol) {
+		if sym == nil || sym.Name != name {
+			return
+		}
+		add(sym, moduleTarget(file.File, name))
+	}
+	typeRefs := func(tn dang.TypeNode) {
+		for _, named := range namedTypeNodes(tn) {
+			typeName(&dang.Symbol{Name: named.Name, Loc: named.Loc})
+		}
+	}
+
+	file.File.AST.Walk(func(n dang.Node) bool {
+		if n == nil {
+			return false
+		}
+
+		declared(declarationName(n))
+
+		switch node := n.(type) {
+		case *dang.Symbol:
+			if node.Name == name && node.Loc != nil {
+				pos := Position{Line: node.Loc.Line - 1, Character: node.Loc.Column - 1}
+				add(node, h.nameTarget(file.URI, file.File, pos, name))
+			}
+		case *dang.Select:
+			if node.Field != nil && node.Field.Name == name && node.Receiver != nil {
+				if resolved, ok := h.selectTarget(file.File, node); ok {
+					add(node.Field, resolved)
+				}
+			}
+		case *dang.FieldDecl:
+			typeRefs(node.Type_)
+		case *dang.FunDecl:
+			typeRefs(node.Ret)
+		case *dang.TypeHint:
+			typeRefs(node.Type)
+		case *dang.ObjectDecl:
+			for _, iface := range node.Implements {
+				typeName(iface)
+			}
+		case *dang.InterfaceDecl:
+			for _, iface := range node.Implements {
+				typeName(iface)
+			}
+		case *dang.UnionDecl:
+			for _, member := range node.Members {
+				typeName(member)
+			}
+		case *dang.EnumDecl:
+			for _, value := range node.Values {
+				declared(value)
+			}
+		}
+		return true
+	})
+
+	return refs
+}
+
+// namedTypeNodes returns the unqualified named types an annotation mentions.
+// Qualified names like Dagger.Container belong to an import, not the module.
+func namedTypeNodes(tn dang.TypeNode) []*dang.NamedTypeNode {
+	switch t := tn.(type) {
+	case *dang.NamedTypeNode:
+		if t != nil && t.Base == nil && t.Loc != nil {
+			return []*dang.NamedTypeNode{t}
+		}
+	case *dang.AppliedTypeNode:
+		named := namedTypeNodes(t.Base)
+		for _, arg := range t.Args {
+			named = append(named, namedTypeNodes(arg)...)
+		}
+		return named
+	case dang.ListTypeNode:
+		return namedTypeNodes(t.Elem)
+	case dang.NonNullTypeNode:
+		return namedTypeNodes(t.Elem)
+	case dang.ObjectTypeNode:
+		var named []*dang.NamedTypeNode
+		for _, field := range t.Fields {
+			named = append(named, namedTypeNodes(field.Type)...)
+		}
+		return named
+	case dang.FunTypeNode:
+		var named []*dang.NamedTypeNode
+		for _, arg := range t.Args {
+			if arg != nil {
+				named = append(named, namedTypeNodes(arg.Type_)...)
+			}
+		}
+		return append(named, namedTypeNodes(t.Ret)...)
+	}
+	return nil
+}
@@ -0,0 +1,152 @@
+package lsp
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+	"testing"
+)
+
+// openModule writes files into a fresh module directory and opens them all
+// in a handler.
+func openModule(t *testing.T, files map[string]string) (*langHandler, map[string]DocumentURI) {
+	t.Helper()
+	ctx := context.Background()
+	dir := t.TempDir()
+	h := NewHandler(ctx)
+	uris := map[string]DocumentURI{}
+	for name, text := range files {
+		path := filepath.Join(dir, name)
+		if err := os.WriteFile(path, []byte(text), 0o644); err != nil {
+			t.Fatalf("write %s: %v", name, err)
+		}
+		uris[name] = toURI(path)
+	}
+	for name, uri := range uris {
+		if err := h.openFile(uri, "dang", 1); err != nil {
+			t.Fatalf("open %s: %v", name, err)
+		}
+		version := 1
+		if err := h.updateFile(ctx, uri, files[name], &version); err != nil {
+			t.Fatalf("update %s: %v", name, err)
+		}
+	}
+	return h, uris
+}
+
+var dogModule = map[string]string{
+	"dog.dang": `type Dog {
+  pub name: String! = "Rex"
+  pub sound: String! { "woof" }
+}
+`,
+	"main.dang": `pub rex: Dog! = Dog
+pub speak: String! { rex.sound }
+pub label(d: Dog!): String! { d.name + " says " + d.sound }
+`,
+}
+
+func TestReferencesAcrossFiles(t *testing.T) {
+	ctx := context.Background()
+	h, uris := openModule(t, dogModule)
+
+	// rex.sound in main.dang
+	refs, err := h.findReferences(ctx, uris["main.dang"], Position{Line: 1, Character: 25}, "sound")
+	if err != nil {
+		t.Fatalf("find references: %v", err)
+	}
+	if refs == nil {
+		t.Fatalf("no references found")
+	}
+
+	perFile := map[DocumentURI]int{}
+	var decls int
+	for _, ref := range refs.References {
+		perFile[ref.Location.URI]++
+		if ref.Declaration {
+			decls++
+			if ref.Location.URI != uris["dog.dang"] || ref.Location.Range.Start != (Position{Line: 2, Character: 6}) {
+				t.Errorf("unexpected declaration location: %+v", ref.Location)
+			}
+		}
+	}
+	if decls != 1 || perFile[uris["dog.dang"]] != 1 || perFile[uris["main.dang"]] != 2 {
+		t.Fatalf("unexpected references: %+v", refs.References)
+	}
+}
+
+func TestRenameTypeAcrossFiles(t *testing.T) {
+	ctx := context.Background()
+	h, uris := openModule(t, dogModule)
+
+	// type Dog in dog.dang
+	edit, err := h.rename(ctx, uris["dog.dang"], Position{Line: 0, Character: 5}, "Dog", "Hound")
+	if err != nil {
+		t.Fatalf("rename: %v", err)
+	}
+	changes, ok := edit.Changes.(map[string][]TextEdit)
+	if !ok {
+		t.Fatalf("unexpected changes: %#v", edit.Changes)
+	}
+
+	for name, want := range map[string]string{
+		"dog.dang":  strings.ReplaceAll(dogModule["dog.dang"], "Dog", "Hound"),
+		"main.dang": strings.ReplaceAll(dogModule["main.dang"], "Dog", "Hound"),
+	} {
+		if got := applyEdits(dogModule[name], changes[string(uris[name])]); got != want {
+			t.Errorf("%s after rename:\n%s\nwant:\n%s", name, got, want)
+		}
+	}
+}
+
+func TestRenameRefusesCollision(t *testing.T) {
+	ctx := context.Background()
+	h, uris := openModule(t, dogModule)
+
+	_, err := h.rename(ctx, uris["main.dang"], Position{Line: 1, Character: 25}, "sound", "name")
+	if err == nil || !strings.Contains(err.Error(), "already declared in the same scope") {
+		t.Fatalf("expected a collision error, got %v", err)
+	}
+
+	// Top-level declarations share one scope across the module's files.
+	_, err = h.rename(ctx, uris["main.dang"], Position{Line: 0, Character: 4}, "rex", "Dog")
+	if err == nil || !strings.Contains(err.Error(), "already declared in the same scope") {
+		t.Fatalf("expected a collision error, got %v", err)
+	}
+}
+
+func TestRenameRefusesUndeclared(t *testing.T) {
+	ctx := context.Background()
+	h, uris := openModule(t, map[string]string{
+		"main.dang": "print(\"hi\")\n",
+	})
+
+	_, err := h.rename(ctx, uris["main.dang"], Position{Line: 0, Character: 0}, "print", "say")
+	if err == nil || !strings.Contains(err.Error(), "not declared in this module") {
+		t.Fatalf("expected a refusal, got %v", err)
+	}
+}
+
+// applyEdits applies single-line edits to text.
+func applyEdits(text string, edits []TextEdit) string {
+	edits = slices.Clone(edits)
+	// Apply back to front so earlier edits don't shift later ones.
+	slices.SortFunc(edits, func(a, b TextEdit) int {
+		switch {
+		case positionAfter(a.Range.Start, b.Range.Start):
+			return -1
+		case positionAfter(b.Range.Start, a.Range.Start):
+			return 1
+		}
+		return 0
+	})
+
+	lines := strings.Split(text, "\n")
+	for _, edit := range edits {
+		line := lines[edit.Range.Start.Line]
+		lines[edit.Range.Start.Line] = line[:edit.Range.Start.Character] + edit.NewText + line[edit.Range.End.Character:]
+	}
+	return strings.Join(lines, "\n")
+}
@@ -3,11 +3,17 @@ package lsp
 import (
 	"context"
 	"log/slog"
+	"slices"
+	"unicode"
 
 	"github.com/creachadair/jrpc2"
 	"github.com/vito/dang/v2/pkg/dang"
 )
 
+// requestFailed is the LSP error code for a valid request the server
+// couldn't carry out.
+const requestFailed jrpc2.Code = -32803
+
 func (h *langHandler) handleTextDocumentRename(ctx context.Context, req *jrpc2.Request) (any, error) {
 	if !req.HasParams() {
 		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "missing parameters")
@@ -26,13 +32,6 @@ func (h *langHandler) handleTextDocumentRename(ctx context.Context, req *jrpc2.R
 		return nil, nil
 	}
 
-	if f.AST == nil {
-		slog.WarnContext(ctx, "AST is nil for file", "uri", params.TextDocument.URI)
-		return nil, nil
-	}
-
-	slog.InfoContext(ctx, "file info", "hasAST", f.AST != nil, "textLength", len(f.Text))
-
 	// Find the symbol at the cursor position
 	symbolName := h.symbolAtPosition(f, params.Position)
 	if symbolName == "" {
@@ -40,136 +39,129 @@ func (h *langHandler) handleTextDocumentRename(ctx context.Context, req *jrpc2.R
 		return nil, nil
 	}
 
-	slog.InfoContext(ctx, "renaming symbol", "symbol", symbolName, "newName", params.NewName)
-
-	// Collect all text edits for renaming this symbol
-	var edits []TextEdit
+	return h.rename(ctx, params.TextDocument.URI, params.Position, symbolName, params.NewName)
+}
 
-	defer func() {
-		slog.InfoContext(ctx, "DEFERRED returning workspace edit", "totalEdits", len(edits))
-	}()
+// rename renames the identifier name at pos, along with every use of it
+// across the module, to newName.
+func (h *langHandler) rename(ctx context.Context, uri DocumentURI, pos Position, name, newName string) (*WorkspaceEdit, error) {
+	if !isIdentifier(newName) {
+		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "%q is not a valid name", newName)
+	}
 
-	// Use precise reference finding with Symbol nodes
-	slog.InfoContext(ctx, "about to find references")
-	references := h.findPreciseReferences(f, symbolName)
-	slog.InfoContext(ctx, "found references", "count", len(references), "references", references)
-	for _, refRange := range references {
-		edits = append(edits, TextEdit{
-			Range:   refRange,
-			NewText: params.NewName,
-		})
+	refs, err := h.findReferences(ctx, uri, pos, name)
+	if err != nil {
+		return nil, err
+	}
+	if refs == nil {
+		slog.WarnContext(ctx, "could not resolve symbol for rename", "symbol", name)
+		return nil, nil
 	}
 
-	// Also find and rename declarations
-	declarations := h.findDeclarations(f.AST, symbolName)
-	slog.InfoContext(ctx, "found declarations", "count", len(declarations), "declarations", declarations)
-	for _, declRange := range declarations {
-		edits = append(edits, TextEdit{
-			Range:   declRange,
-			NewText: params.NewName,
-		})
+	// Schema types and fields, builtins, and anything else the module
+	// doesn't declare can't be renamed from here.
+	if !refs.Target.owned() {
+		return nil, jrpc2.Errorf(requestFailed, "cannot rename %s: it is not declared in this module", name)
+	}
+	if !slices.ContainsFunc(refs.References, func(ref reference) bool { return ref.Declaration }) {
+		return nil, jrpc2.Errorf(requestFailed, "cannot rename %s: its declaration could not be found", name)
 	}
 
-	slog.InfoContext(ctx, "returning workspace edit", "totalEdits", len(edits), "edits", edits)
+	if newName != name && slices.Contains(scopeNames(refs.Files, refs.Target.Declaration), newName) {
+		return nil, jrpc2.Errorf(requestFailed, "cannot rename %s to %s: %s is already declared in the same scope", name, newName, newName)
+	}
 
-	// Return WorkspaceEdit with changes for this file
-	changes := map[string][]TextEdit{
-		string(params.TextDocument.URI): edits,
+	changes := map[string][]TextEdit{}
+	for _, ref := range refs.References {
+		fileURI := string(ref.Location.URI)
+		changes[fileURI] = append(changes[fileURI], TextEdit{
+			Range:   ref.Location.Range,
+			NewText: newName,
+		})
 	}
 
+	slog.InfoContext(ctx, "returning workspace edit", "files", len(changes), "edits", len(refs.References))
+
 	return &WorkspaceEdit{
 		Changes: changes,
 	}, nil
 }
 
-// findDeclarations finds all declaration nodes for a symbol
-func (h *langHandler) findDeclarations(node dang.Node, symbolName string) []Range {
-	var declarations []Range
-
-	if node == nil {
-		return declarations
-	}
-
-	// Walk the tree and collect all declarations
-	node.Walk(func(n dang.Node) bool {
-		if n == nil {
-			return false
+// scopeNames returns the names declared in the same scope as the declaration
+// at decl: its fellow arguments, the other declarations of its block or type
+// body, or, for a top-level declaration, every top-level declaration in the
+// module, since sibling files share one scope.
+func scopeNames(files []moduleFile, decl Location) []string {
+	for _, file := range files {
+		if file.URI != decl.URI {
+			continue
 		}
-
-		// Check if this node declares the symbol
-		declaredSyms := n.DeclaredSymbols()
-		for _, declSym := range declaredSyms {
-			if declSym == symbolName {
-				var loc *dang.SourceLocation
-				// Special handling for FieldDecl: use the Name symbol's location
-				if fieldDecl, ok := n.(*dang.FieldDecl); ok {
-					loc = fieldDecl.Name.GetSourceLocation()
-				} else {
-					loc = n.GetSourceLocation()
-				}
-
-				if loc != nil {
-					declarations = append(declarations, Range{
-						Start: Position{Line: loc.Line - 1, Character: loc.Column - 1},
-						End:   Position{Line: loc.Line - 1, Character: loc.Column - 1 + len(symbolName)},
-					})
-				}
+		scope, topLevel := declarationScope(file, decl)
+		if topLevel {
+			scope = nil
+			for _, sibling := range files {
+				scope = append(scope, formNames(sibling.File.AST.Forms)...)
 			}
 		}
-
-		return true // Continue walking children
-	})
-
-	return declarations
+		var names []string
+		for _, sym := range scope {
+			names = append(names, sym.Name)
+		}
+		return names
+	}
+	return nil
 }
 
-// Helper to find symbol nodes in the AST
-func (h *langHandler) findSymbolNodes(node dang.Node, symbolName string) []*dang.Symbol {
-	var symbols []*dang.Symbol
-
-	if node == nil {
-		return symbols
+// declarationScope returns the names declared alongside decl in file, and
+// whether they are the file's top-level declarations.
+func declarationScope(file moduleFile, decl Location) ([]*dang.Symbol, bool) {
+	declares := func(sym *dang.Symbol) bool {
+		loc := symbolLocation(file.URI, sym)
+		return loc != nil && *loc == decl
 	}
 
-	// Walk the tree and collect all matching symbols
-	node.Walk(func(n dang.Node) bool {
-		if n == nil {
+	var scope []*dang.Symbol
+	var topLevel, found bool
+	file.File.AST.Walk(func(n dang.Node) bool {
+		if n == nil || found {
 			return false
 		}
-
-		// Check if this node is a symbol matching our name
-		if sym, ok := n.(*dang.Symbol); ok {
-			if sym.Name == symbolName {
-				symbols = append(symbols, sym)
+		var names []*dang.Symbol
+		switch typed := n.(type) {
+		case *dang.FunDecl:
+			args := typed.Args
+			if typed.BlockParam != nil {
+				args = append(slices.Clip(args), typed.BlockParam)
 			}
+			names = fieldNames(args)
+		case *dang.BlockArg:
+			names = fieldNames(typed.Args)
+		case *dang.Block:
+			names = formNames(typed.Forms)
+		case *dang.FileBlock:
+			names = formNames(typed.Forms)
+		case *dang.EnumDecl:
+			names = typed.Values
 		}
-
-		return true // Continue walking children
+		if !slices.ContainsFunc(names, declares) {
+			return true
+		}
+		scope, found = names, true
+		_, topLevel = n.(*dang.FileBlock)
+		return false
 	})
-
-	return symbols
+	return scope, topLevel
 }
 
-// More precise reference finding using symbol nodes
-func (h *langHandler) findPreciseReferences(f *File, symbolName string) []Range {
-	var references []Range
-
-	if f.AST == nil {
-		return references
+// isIdentifier reports whether name is usable as a Dang identifier.
+func isIdentifier(name string) bool {
+	if name == "" || unicode.IsDigit(rune(name[0])) {
+		return false
 	}
-
-	// Find all symbol nodes that match the symbol name
-	symbols := h.findSymbolNodes(f.AST, symbolName)
-
-	for _, sym := range symbols {
-		loc := sym.GetSourceLocation()
-		if loc != nil {
-			references = append(references, Range{
-				Start: Position{Line: loc.Line - 1, Character: loc.Column - 1},
-				End:   Position{Line: loc.Line - 1, Character: loc.Column - 1 + len(symbolName)},
-			})
+	for _, r := range name {
+		if !isIdentifierChar(r) {
+			return false
 		}
 	}
-
-	return references
+	return true
 }
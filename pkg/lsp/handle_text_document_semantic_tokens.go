@@ -0,0 +1,419 @@
+package lsp
+
+import (
+	"context"
+	"slices"
+	"sort"
+	"strings"
+	"unicode"
+
+	"github.com/creachadair/jrpc2"
+	"github.com/vito/dang/v2/pkg/dang"
+	"github.com/vito/dang/v2/pkg/hm"
+)
+
+// Semantic token types, in legend order.
+const (
+	semNamespace = iota
+	semType
+	semInterface
+	semEnum
+	semEnumMember
+	semFunction
+	semMethod
+	semProperty
+	semParameter
+	semVariable
+	semKeyword
+	semString
+	semNumber
+	semComment
+	semDecorator
+)
+
+// Semantic token modifiers, as bits.
+const (
+	semDeclaration uint32 = 1 << iota
+)
+
+var semanticTokensLegend = SemanticTokensLegend{
+	TokenTypes: []string{
+		"namespace",
+		"type",
+		"interface",
+		"enum",
+		"enumMember",
+		"function",
+		"method",
+		"property",
+		"parameter",
+		"variable",
+		"keyword",
+		"string",
+		"number",
+		"comment",
+		"decorator",
+	},
+	TokenModifiers: []string{
+		"declaration",
+	},
+}
+
+func (h *langHandler) handleTextDocumentSemanticTokensFull(ctx context.Context, req *jrpc2.Request) (any, error) {
+	if !req.HasParams() {
+		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "missing parameters")
+	}
+
+	var params SemanticTokensParams
+	if err := req.UnmarshalParams(&params); err != nil {
+		return nil, err
+	}
+
+	f := h.waitForFile(params.TextDocument.URI)
+	if f == nil {
+		return nil, nil
+	}
+
+	return &SemanticTokens{
+		Data: encodeSemanticTokens(semanticTokens(f)),
+	}, nil
+}
+
+func (h *langHandler) handleTextDocumentSemanticTokensRange(ctx context.Context, req *jrpc2.Request) (any, error) {
+	if !req.HasParams() {
+		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "missing parameters")
+	}
+
+	var params SemanticTokensRangeParams
+	if err := req.UnmarshalParams(&params); err != nil {
+		return nil, err
+	}
+
+	f := h.waitForFile(params.TextDocument.URI)
+	if f == nil {
+		return nil, nil
+	}
+
+	var inRange []semanticToken
+	for _, tok := range semanticTokens(f) {
+		tokRange := Range{
+			Start: Position{Line: tok.Line, Character: tok.Col},
+			End:   Position{Line: tok.Line, Character: tok.Col + tok.Len},
+		}
+		if rangesOverlap(tokRange, params.Range) {
+			inRange = append(inRange, tok)
+		}
+	}
+
+	return &SemanticTokens{
+		Data: encodeSemanticTokens(inRange),
+	}, nil
+}
+
+// semanticToken is a classified run of text on a single line.
+type semanticToken struct {
+	Line, Col, Len int
+	Type           int
+	Modifiers      uint32
+}
+
+// semanticRole is how the AST classifies an identifier.
+type semanticRole struct {
+	Type      int
+	Modifiers uint32
+}
+
+// semanticTokens classifies every token in the file, in document order.
+// Tokens and their lexical classes come from the same tree-sitter
+// highlighting the editors and the REPL use; identifiers take their role from
+// the resolved AST where it places them. Tree-sitter needs cgo, so without it
+// no tokens are served.
+func semanticTokens(f *File) []semanticToken {
+	roles := semanticRoles(f)
+
+	// Highlight addresses runes; tokens address bytes, like the AST.
+	byteOffsets := make([]int, 0, len(f.Text)+1)
+	for i := range f.Text {
+		byteOffsets = append(byteOffsets, i)
+	}
+	byteOffsets = append(byteOffsets, len(f.Text))
+
+	lineStarts := []int{0}
+	for i := 0; i < len(f.Text); i++ {
+		if f.Text[i] == '\n' {
+			lineStarts = append(lineStarts, i+1)
+		}
+	}
+
+	var tokens []semanticToken
+	for _, span := range dang.Highlight(f.Text) {
+		start, end := byteOffsets[span.Start], byteOffsets[span.End]
+		typ, ok := highlightTokenType(span.Class, f.Text[start:end])
+		if !ok {
+			continue
+		}
+		// Tokens can't span lines, so split strings and comments that do.
+		for start < end {
+			line := sort.Search(len(lineStarts), func(i int) bool { return lineStarts[i] > start }) - 1
+			segEnd := end
+			if nl := strings.IndexByte(f.Text[start:end], '\n'); nl >= 0 {
+				segEnd = start + nl
+			}
+			if segEnd > start {
+				tok := semanticToken{Line: line, Col: start - lineStarts[line], Len: segEnd - start, Type: typ}
+				if role, ok := roles[Position{Line: tok.Line, Character: tok.Col}]; ok {
+					tok.Type, tok.Modifiers = role.Type, role.Modifiers
+				}
+				tokens = append(tokens, tok)
+			}
+			start = segEnd + 1
+		}
+	}
+	return tokens
+}
+
+// highlightTokenType maps a highlight class onto a semantic token type, for
+// when the AST doesn't place the token. Operators and punctuation aren't
+// tokens.
+func highlightTokenType(class, text string) (int, bool) {
+	switch class {
+	case dang.ClassString, dang.ClassEscape, dang.ClassLabel:
+		return semString, true
+	case dang.ClassComment:
+		return semComment, true
+	case dang.ClassDirective:
+		return semDecorator, true
+	case dang.ClassNumber:
+		switch text {
+		case "true", "false", "null":
+			return semKeyword, true
+		}
+		return semNumber, true
+	case dang.ClassKeyword, dang.ClassSelf:
+		return semKeyword, true
+	case dang.ClassType:
+		return semType, true
+	case dang.ClassFunction, dang.ClassBuiltin:
+		return semFunction, true
+	case dang.ClassProperty:
+		return semProperty, true
+	case dang.ClassVariable:
+		return semVariable, true
+	}
+	return 0, false
+}
+
+// encodeSemanticTokens packs tokens into the LSP's relative encoding: five
+// integers per token, each position relative to the token before it.
+func encodeSemanticTokens(tokens []semanticToken) []uint32 {
+	data := make([]uint32, 0, len(tokens)*5)
+	prevLine, prevCol := 0, 0
+	for _, tok := range tokens {
+		deltaLine := tok.Line - prevLine
+		deltaCol := tok.Col
+		if deltaLine == 0 {
+			deltaCol -= prevCol
+		}
+		data = append(data,
+			uint32(deltaLine),
+			uint32(deltaCol),
+			uint32(tok.Len),
+			uint32(tok.Type),
+			tok.Modifiers,
+		)
+		prevLine, prevCol = tok.Line, tok.Col
+	}
+	return data
+}
+
+// semanticRoles walks the resolved AST and records the role of each
+// identifier it can place, keyed by the identifier's start.
+func semanticRoles(f *File) map[Position]semanticRole {
+	roles := map[Position]semanticRole{}
+	if f.AST == nil {
+		return roles
+	}
+
+	// The walk visits parents before children, and a parent knows more
+	// about its children than they know about themselves (a FieldDecl
+	// doesn't know it's a parameter), so the first role recorded wins.
+	mark := func(sym *dang.Symbol, typ int, mods uint32) {
+		if sym == nil || sym.Loc == nil {
+			return
+		}
+		pos := Position{Line: sym.Loc.Line - 1, Character: sym.Loc.Column - 1}
+		if _, ok := roles[pos]; !ok {
+			roles[pos] = semanticRole{Type: typ, Modifiers: mods}
+		}
+	}
+	markTypes := func(tn dang.TypeNode) {
+		for _, named := range namedTypeNodes(tn) {
+			mark(&dang.Symbol{Name: named.Name, Loc: named.Loc}, semType, 0)
+		}
+	}
+	markMembers := func(body *dang.Block) {
+		if body == nil {
+			return
+		}
+		for _, decl := range fieldDecls(body.Forms) {
+			if _, ok := decl.Value.(*dang.FunDecl); ok {
+				mark(decl.Name, semMethod, semDeclaration)
+			} else {
+				mark(decl.Name, semProperty, semDeclaration)
+			}
+		}
+	}
+
+	// Scopes that bind parameters, to mark the parameters' uses once the
+	// walk is done.
+	type paramScope struct {
+		body dang.Node
+		args []*dang.FieldDecl
+	}
+	var paramScopes []paramScope
+
+	f.AST.Walk(func(n dang.Node) bool {
+		if n == nil {
+			return false
+		}
+		switch node := n.(type) {
+		case *dang.ImportDecl:
+			mark(node.Name, semNamespace, semDeclaration)
+		case *dang.ObjectDecl:
+			mark(node.Name, semType, semDeclaration)
+			markMembers(node.Value)
+			for _, iface := range node.Implements {
+				mark(iface, semInterface, 0)
+			}
+		case *dang.InterfaceDecl:
+			mark(node.Name, semInterface, semDeclaration)
+			markMembers(node.Value)
+			for _, iface := range node.Implements {
+				mark(iface, semInterface, 0)
+			}
+		case *dang.ScalarDecl:
+			mark(node.Name, semType, semDeclaration)
+			markMembers(node.Value)
+		case *dang.EnumDecl:
+			mark(node.Name, semEnum, semDeclaration)
+			for _, value := range node.Values {
+				mark(value, semEnumMember, semDeclaration)
+			}
+		case *dang.UnionDecl:
+			mark(node.Name, semType, semDeclaration)
+			for _, member := range node.Members {
+				mark(member, semType, 0)
+			}
+		case *dang.FunDecl:
+			args := node.Args
+			if node.BlockParam != nil {
+				args = append(slices.Clip(args), node.BlockParam)
+			}
+			for _, arg := range args {
+				mark(arg.Name, semParameter, semDeclaration)
+			}
+			markTypes(node.Ret)
+			paramScopes = append(paramScopes, paramScope{body: node.FunctionBase.Body, args: args}) //nolint:staticcheck // Body() method shadows Body field
+		case *dang.BlockArg:
+			for _, arg := range node.Args {
+				mark(arg.Name, semParameter, semDeclaration)
+			}
+			paramScopes = append(paramScopes, paramScope{body: node.BodyNode, args: node.Args})
+		case *dang.FieldDecl:
+			if _, ok := node.Value.(*dang.FunDecl); ok {
+				mark(node.Name, semFunction, semDeclaration)
+			} else {
+				mark(node.Name, semVariable, semDeclaration)
+			}
+			markTypes(node.Type_)
+		case *dang.TypeHint:
+			markTypes(node.Type)
+		case *dang.FunCall:
+			switch fun := node.Fun.(type) {
+			case *dang.Symbol:
+				if !unicode.IsUpper(rune(fun.Name[0])) {
+					mark(fun, semFunction, 0)
+				}
+			case *dang.Select:
+				mark(fun.Field, semMethod, 0)
+			}
+		case *dang.Select:
+			mark(node.Field, selectRole(node), 0)
+		case *dang.Symbol:
+			switch {
+			case unicode.IsUpper(rune(node.Name[0])):
+				mark(node, semType, 0)
+			case isFunctionSymbol(f, node.Name):
+				mark(node, semFunction, 0)
+			default:
+				mark(node, semVariable, 0)
+			}
+		}
+		return true
+	})
+
+	// Outer scopes come first, so an inner scope's parameters override the
+	// outer ones they shadow.
+	for _, scope := range paramScopes {
+		if scope.body == nil {
+			continue
+		}
+		names := map[string]bool{}
+		for _, arg := range scope.args {
+			if arg.Name != nil {
+				names[arg.Name.Name] = true
+			}
+		}
+		scope.body.Walk(func(n dang.Node) bool {
+			if sym, ok := n.(*dang.Symbol); ok && names[sym.Name] && sym.Loc != nil {
+				pos := Position{Line: sym.Loc.Line - 1, Character: sym.Loc.Column - 1}
+				roles[pos] = semanticRole{Type: semParameter}
+			}
+			return n != nil
+		})
+	}
+
+	return roles
+}
+
+// selectRole classifies a selected member by its receiver's type.
+func selectRole(sel *dang.Select) int {
+	if sel.Receiver == nil {
+		return semProperty
+	}
+	mod := namedType(sel.Receiver.GetInferredType())
+	if mod == nil {
+		return semProperty
+	}
+	scheme, ok := mod.SchemeOf(sel.Field.Name)
+	if !ok {
+		return semProperty
+	}
+	t, _ := scheme.Type()
+	if isFunctionType(t) {
+		return semMethod
+	}
+	// Enum values are bindings of the enum's own type on the enum.
+	if member := namedType(t); member == mod && member.Kind == dang.EnumKind {
+		return semEnumMember
+	}
+	return semProperty
+}
+
+// isFunctionSymbol reports whether name refers to a function declared in the
+// module.
+func isFunctionSymbol(f *File, name string) bool {
+	if f.Symbols == nil {
+		return false
+	}
+	def, ok := f.Symbols.Definitions[name]
+	return ok && def.Kind == FunctionCompletion
+}
+
+func isFunctionType(t hm.Type) bool {
+	if nn, ok := t.(hm.NonNullType); ok {
+		t = nn.Type
+	}
+	_, ok := t.(*hm.FunctionType)
+	return ok
+}
@@ -0,0 +1,22 @@
+package lsp
+
+import (
+	"slices"
+	"testing"
+)
+
+func TestEncodeSemanticTokens(t *testing.T) {
+	data := encodeSemanticTokens([]semanticToken{
+		{Line: 1, Col: 2, Len: 3, Type: semKeyword},
+		{Line: 1, Col: 6, Len: 4, Type: semVariable, Modifiers: semDeclaration},
+		{Line: 3, Col: 1, Len: 2, Type: semNumber},
+	})
+	want := []uint32{
+		1, 2, 3, semKeyword, 0,
+		0, 4, 4, semVariable, semDeclaration,
+		2, 1, 2, semNumber, 0,
+	}
+	if !slices.Equal(data, want) {
+		t.Fatalf("encoded = %v, want %v", data, want)
+	}
+}
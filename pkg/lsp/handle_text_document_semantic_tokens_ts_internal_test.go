@@ -0,0 +1,132 @@
+//go:build cgo
+
+package lsp
+
+import (
+	"testing"
+
+	"github.com/vito/dang/v2/pkg/dang"
+)
+
+// fileFor builds a File the way the handler does, tolerating text that
+// doesn't parse, which tree-sitter still classifies.
+func fileFor(t *testing.T, text string) *File {
+	t.Helper()
+	f := &File{Text: text}
+	if parsed, err := dang.Parse("test.dang", []byte(text)); err == nil {
+		f.AST = parsed.(*dang.FileBlock)
+	}
+	return f
+}
+
+// tokenAt returns the token covering the given column of a line.
+func tokenAt(tokens []semanticToken, line, col int) (semanticToken, bool) {
+	for _, tok := range tokens {
+		if tok.Line == line && col >= tok.Col && col < tok.Col+tok.Len {
+			return tok, true
+		}
+	}
+	return semanticToken{}, false
+}
+
+func TestSemanticTokensClassifyByRole(t *testing.T) {
+	text := `# greeting
+type Dog {
+  pub name: String! = "Rex"
+  pub bark(times: Int!): String! { name + times.toString }
+}
+let count = 42
+`
+	f := fileFor(t, text)
+	if f.AST == nil {
+		t.Fatal("expected the text to parse")
+	}
+
+	got := map[Position]semanticToken{}
+	for _, tok := range semanticTokens(f) {
+		got[Position{Line: tok.Line, Character: tok.Col}] = tok
+	}
+
+	for _, want := range []semanticToken{
+		{Line: 0, Col: 0, Len: 10, Type: semComment},
+		{Line: 1, Col: 0, Len: 4, Type: semKeyword},
+		{Line: 1, Col: 5, Len: 3, Type: semType, Modifiers: semDeclaration},
+		{Line: 2, Col: 2, Len: 3, Type: semKeyword},
+		{Line: 2, Col: 6, Len: 4, Type: semProperty, Modifiers: semDeclaration},
+		{Line: 2, Col: 12, Len: 6, Type: semType},
+		{Line: 2, Col: 22, Len: 5, Type: semString},
+		{Line: 3, Col: 6, Len: 4, Type: semMethod, Modifiers: semDeclaration},
+		{Line: 3, Col: 11, Len: 5, Type: semParameter, Modifiers: semDeclaration},
+		{Line: 3, Col: 18, Len: 3, Type: semType},
+		{Line: 3, Col: 35, Len: 4, Type: semVariable},
+		{Line: 3, Col: 42, Len: 5, Type: semParameter},
+		{Line: 3, Col: 48, Len: 8, Type: semProperty},
+		{Line: 5, Col: 0, Len: 3, Type: semKeyword},
+		{Line: 5, Col: 4, Len: 5, Type: semVariable, Modifiers: semDeclaration},
+		{Line: 5, Col: 12, Len: 2, Type: semNumber},
+	} {
+		pos := Position{Line: want.Line, Character: want.Col}
+		if got[pos] != want {
+			t.Errorf("token at %d:%d = %+v, want %+v", want.Line, want.Col, got[pos], want)
+		}
+	}
+}
+
+func TestSemanticTokensTemplateInterpolation(t *testing.T) {
+	text := "let t = `hi ${count} there` # done"
+	tokens := semanticTokens(fileFor(t, text))
+
+	for _, want := range []struct {
+		col int
+		typ int
+	}{
+		{col: 0, typ: semKeyword},
+		{col: 9, typ: semString},
+		{col: 14, typ: semVariable},
+		{col: 22, typ: semString},
+		{col: 28, typ: semComment},
+	} {
+		tok, ok := tokenAt(tokens, 0, want.col)
+		if !ok || tok.Type != want.typ {
+			t.Errorf("token at col %d = %+v (found: %v), want type %d", want.col, tok, ok, want.typ)
+		}
+	}
+}
+
+func TestSemanticTokensTemplateBackslashes(t *testing.T) {
+	text := "let p = `C:\\` + `a \\${b}` + name"
+	tokens := semanticTokens(fileFor(t, text))
+
+	// The first template closes after its backslash, and the second one's
+	// \${ is an escape, so b is template text rather than an interpolation.
+	for _, want := range []struct {
+		col int
+		typ int
+	}{
+		{col: 10, typ: semString},
+		{col: 20, typ: semString},
+		{col: 22, typ: semString},
+		{col: 29, typ: semVariable},
+	} {
+		tok, ok := tokenAt(tokens, 0, want.col)
+		if !ok || tok.Type != want.typ {
+			t.Errorf("token at col %d = %+v (found: %v), want type %d", want.col, tok, ok, want.typ)
+		}
+	}
+}
+
+func TestSemanticTokensSplitMultilineStrings(t *testing.T) {
+	text := "\"\"\"\nfirst\n  second\n\"\"\"\n"
+	tokens := semanticTokens(fileFor(t, text))
+
+	for _, want := range []semanticToken{
+		{Line: 0, Col: 0, Len: 3, Type: semString},
+		{Line: 1, Col: 0, Len: 5, Type: semString},
+		{Line: 2, Col: 0, Len: 8, Type: semString},
+		{Line: 3, Col: 0, Len: 3, Type: semString},
+	} {
+		if tok, ok := tokenAt(tokens, want.Line, want.Col); !ok || tok != want {
+			t.Errorf("token at %d:%d = %+v, want %+v", want.Line, want.Col, tok, want)
+		}
+	}
+}
@@ -0,0 +1,57 @@
+package lsp
+
+import (
+	"context"
+
+	"github.com/creachadair/jrpc2"
+	"github.com/vito/dang/v2/pkg/dang"
+)
+
+func (h *langHandler) handleTextDocumentSignatureHelp(ctx context.Context, req *jrpc2.Request) (any, error) {
+	if !req.HasParams() {
+		return nil, jrpc2.Errorf(jrpc2.InvalidParams, "missing parameters")
+	}
+
+	var params SignatureHelpParams
+	if err := req.UnmarshalParams(&params); err != nil {
+		return nil, err
+	}
+
+	f := h.waitForFile(params.TextDocument.URI)
+	if f == nil {
+		return nil, nil
+	}
+
+	env := h.buildCompletionTypeScope(f, params.Position)
+	if env == nil {
+		return nil, nil
+	}
+
+	sig := dang.SignatureAt(ctx, env, f.Text, params.Position.Line, params.Position.Character)
+	if sig == nil {
+		return nil, nil
+	}
+	return signatureToHelp(sig), nil
+}
+
+// signatureToHelp converts a dang.Signature into its LSP representation.
+func signatureToHelp(sig *dang.Signature) *SignatureHelp {
+	info := SignatureInformation{
+		Label:      sig.Label,
+		Parameters: []ParameterInformation{},
+	}
+	if sig.Documentation != "" {
+		info.Documentation = &MarkupContent{Kind: Markdown, Value: sig.Documentation}
+	}
+	for _, p := range sig.Params {
+		param := ParameterInformation{Label: [2]int{p.Start, p.End}}
+		if p.Documentation != "" {
+			param.Documentation = &MarkupContent{Kind: Markdown, Value: p.Documentation}
+		}
+		info.Parameters = append(info.Parameters, param)
+	}
+	return &SignatureHelp{
+		Signatures:      []SignatureInformation{info},
+		ActiveParameter: sig.ActiveParam,
+	}
+}
@@ -2,10 +2,16 @@ package lsp
 
 import (
 	"context"
+	"io/fs"
 	"log/slog"
+	"path/filepath"
+	"sort"
 	"strings"
+	"unicode"
+	"unicode/utf8"
 
 	"github.com/creachadair/jrpc2"
+	"github.com/vito/dang/v2/pkg/dang"
 )
 
 func (h *langHandler) handleWorkspaceSymbol(ctx context.Context, req *jrpc2.Request) (any, error) {
@@ -20,40 +26,115 @@ func (h *langHandler) handleWorkspaceSymbol(ctx context.Context, req *jrpc2.Requ
 
 	slog.InfoContext(ctx, "workspace symbol request", "query", params.Query)
 
-	var symbols []SymbolInformation
+	symbols := h.workspaceSymbols(params.Query)
+
+	slog.InfoContext(ctx, "workspace symbol results", "query", params.Query, "total", len(symbols))
+
+	return symbols, nil
+}
 
-	// Search all open files for symbols matching the query.
-	query := strings.ToLower(params.Query)
+// workspaceSymbols returns the declarations in every .dang file of the
+// workspace whose name matches query.
+func (h *langHandler) workspaceSymbols(query string) []SymbolInformation {
+	symbols := []SymbolInformation{}
+	for _, path := range h.workspaceDangFiles() {
+		block, err := h.parseFileCached(path)
+		if err != nil {
+			continue
+		}
+		for _, sym := range declarationSymbols(toURI(path), block.Forms) {
+			if fuzzyMatch(query, sym.Name) {
+				symbols = append(symbols, sym)
+			}
+		}
+	}
+	return symbols
+}
 
+// workspaceDangFiles returns every .dang file under the workspace folders,
+// skipping hidden directories, along with any open buffers outside of them.
+func (h *langHandler) workspaceDangFiles() []string {
 	h.mu.Lock()
-	files := make(map[DocumentURI]*File, len(h.files))
-	for uri, file := range h.files {
-		files[uri] = file
+	folders := append([]string(nil), h.folders...)
+	var open []string
+	for uri := range h.files {
+		if path, err := fromURI(uri); err == nil {
+			open = append(open, path)
+		}
 	}
 	h.mu.Unlock()
 
-	for uri, file := range files {
-		snapshot := file.waitForSnapshot()
-		if snapshot.Symbols == nil {
-			continue
+	seen := map[string]bool{}
+	var paths []string
+	add := func(path string) {
+		path = filepath.Clean(path)
+		if filepath.Ext(path) != ".dang" || seen[path] {
+			return
 		}
+		seen[path] = true
+		paths = append(paths, path)
+	}
 
-		// Search through all defined symbols in this file
-		for name, info := range snapshot.Symbols.Definitions {
-			// Fuzzy match: check if query appears anywhere in the symbol name (case-insensitive)
-			if query == "" || strings.Contains(strings.ToLower(name), query) {
-				symbols = append(symbols, SymbolInformation{
-					Name:     name,
-					Kind:     int64(info.Kind),
-					Location: *info.Location,
-				})
+	for _, folder := range folders {
+		_ = filepath.WalkDir(folder, func(path string, d fs.DirEntry, err error) error {
+			if err != nil {
+				return nil
 			}
-		}
-
-		slog.InfoContext(ctx, "searched file", "uri", uri, "definitions", len(snapshot.Symbols.Definitions), "matches", len(symbols))
+			if d.IsDir() {
+				if path != folder && strings.HasPrefix(d.Name(), ".") {
+					return filepath.SkipDir
+				}
+				return nil
+			}
+			add(path)
+			return nil
+		})
+	}
+	for _, path := range open {
+		add(path)
 	}
 
-	slog.InfoContext(ctx, "workspace symbol results", "query", params.Query, "total", len(symbols))
+	sort.Strings(paths)
+	return paths
+}
 
-	return symbols, nil
+// declarationSymbols flattens a file's outline, naming each member's type
+// as its container.
+func declarationSymbols(uri DocumentURI, forms []dang.Node) []SymbolInformation {
+	var symbols []SymbolInformation
+	for _, decl := range documentSymbols(forms) {
+		symbols = append(symbols, SymbolInformation{
+			Name:     decl.Name,
+			Kind:     decl.Kind,
+			Location: Location{URI: uri, Range: decl.SelectionRange},
+		})
+		for _, member := range decl.Children {
+			symbols = append(symbols, SymbolInformation{
+				Name:          member.Name,
+				Kind:          member.Kind,
+				Location:      Location{URI: uri, Range: member.SelectionRange},
+				ContainerName: &decl.Name,
+			})
+		}
+	}
+	return symbols
+}
+
+// fuzzyMatch reports whether query's characters appear in order within name,
+// ignoring case, so that "Ctd" matches "Containerd".
+func fuzzyMatch(query, name string) bool {
+	for _, q := range query {
+		q = unicode.ToLower(q)
+		for {
+			r, size := utf8.DecodeRuneInString(name)
+			if size == 0 {
+				return false
+			}
+			name = name[size:]
+			if unicode.ToLower(r) == q {
+				break
+			}
+		}
+	}
+	return true
 }
@@ -0,0 +1,84 @@
+package lsp
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestWorkspaceSymbols(t *testing.T) {
+	h, uris := openModule(t, dogModule)
+
+	// Files under a workspace folder are searched whether or not they're open.
+	folder, err := fromURI(uris["dog.dang"])
+	if err != nil {
+		t.Fatalf("from uri: %v", err)
+	}
+	folder = filepath.Dir(folder)
+	sub := filepath.Join(folder, "runtime")
+	if err := os.Mkdir(sub, 0o755); err != nil {
+		t.Fatalf("mkdir: %v", err)
+	}
+	if err := os.WriteFile(filepath.Join(sub, "containerd.dang"), []byte("type Containerd {\n  pub start: String! { \"ok\" }\n}\n\nlet socket = \"/run/containerd.sock\"\n"), 0o644); err != nil {
+		t.Fatalf("write: %v", err)
+	}
+	h.addFolder(folder)
+
+	kinds := map[string]SymbolKind{}
+	containers := map[string]string{}
+	for _, sym := range h.workspaceSymbols("") {
+		kinds[sym.Name] = sym.Kind
+		if sym.ContainerName != nil {
+			containers[sym.Name] = *sym.ContainerName
+		}
+	}
+	for name, want := range map[string]SymbolKind{
+		"Dog":        ClassSymbol,
+		"name":       FieldSymbol,
+		"sound":      MethodSymbol,
+		"rex":        ConstantSymbol,
+		"label":      MethodSymbol,
+		"Containerd": ClassSymbol,
+		"start":      MethodSymbol,
+		"socket":     ConstantSymbol,
+	} {
+		if got, ok := kinds[name]; !ok {
+			t.Errorf("missing symbol %q", name)
+		} else if got != want {
+			t.Errorf("%s: kind = %d, want %d", name, got, want)
+		}
+	}
+	if containers["sound"] != "Dog" {
+		t.Errorf("sound: container = %q, want Dog", containers["sound"])
+	}
+	if _, ok := kinds["d"]; ok {
+		t.Errorf("function arguments should not be listed")
+	}
+
+	matches := h.workspaceSymbols("Ctd")
+	if len(matches) != 1 || matches[0].Name != "Containerd" {
+		t.Fatalf("Ctd: got %+v, want only Containerd", matches)
+	}
+	loc := matches[0].Location
+	if loc.Range.Start != (Position{Line: 0, Character: 5}) || loc.Range.End != (Position{Line: 0, Character: 15}) {
+		t.Errorf("Containerd range = %+v", loc.Range)
+	}
+}
+
+func TestFuzzyMatch(t *testing.T) {
+	for _, tc := range []struct {
+		query, name string
+		want        bool
+	}{
+		{"", "anything", true},
+		{"Ctd", "Containerd", true},
+		{"ctd", "Containerd", true},
+		{"wE", "withExec", true},
+		{"dtc", "Containerd", false},
+		{"containers", "Containerd", false},
+	} {
+		if got := fuzzyMatch(tc.query, tc.name); got != tc.want {
+			t.Errorf("fuzzyMatch(%q, %q) = %v, want %v", tc.query, tc.name, got, tc.want)
+		}
+	}
+}
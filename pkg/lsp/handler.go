@@ -11,6 +11,7 @@ import (
 	"path/filepath"
 	"slices"
 	"sort"
+	"strings"
 	"sync"
 	"time"
 	"unicode"
@@ -29,6 +30,7 @@ func NewHandler(rootCtx context.Context) *langHandler {
 		importCache:        make(map[string][]dang.ImportConfig),
 		schemaModuleCaches: make(map[string]*sync.Map),
 		parseCache:         make(map[string]parsedFile),
+		signatures:         make(map[string]string),
 		mu:                 new(sync.Mutex),
 	}
 
@@ -67,6 +69,11 @@ type langHandler struct {
 	// trees turns directory analysis from O(files) into O(1) parses.
 	parseCache map[string]parsedFile
 
+	// Fingerprints of the declarations each open file last exported, keyed by
+	// absolute file path. When an edit changes one, the file's open siblings
+	// are re-checked; edits that only touch bodies leave them alone.
+	signatures map[string]string
+
 	// TODO?: make per-file or something
 	mu *sync.Mutex
 }
@@ -166,6 +173,9 @@ func toURI(path string) DocumentURI {
 func (h *langHandler) closeFile(uri DocumentURI) error {
 	h.mu.Lock()
 	delete(h.files, uri)
+	if path, err := fromURI(uri); err == nil {
+		delete(h.signatures, filepath.Clean(path))
+	}
 	h.mu.Unlock()
 	return nil
 }
@@ -217,11 +227,39 @@ func (h *langHandler) updateFile(ctx context.Context, uri DocumentURI, text stri
 
 	slog.InfoContext(ctx, "file updated", "path", fp)
 
+	changed, err := h.checkFile(ctx, uri, f, fp)
+	if err != nil {
+		return err
+	}
+
+	// Siblings see this file's declarations, not its bodies, so they only
+	// need re-checking when those change. Re-checking them doesn't cascade
+	// further: each one re-infers every sibling's declarations anyway.
+	if changed {
+		for _, sibling := range h.openSiblings(uri, fp) {
+			slog.InfoContext(ctx, "re-checking dependent file", "path", sibling.path)
+			if _, err := h.checkFile(ctx, sibling.uri, sibling.file, sibling.path); err != nil {
+				slog.WarnContext(ctx, "failed to re-check dependent file", "path", sibling.path, "error", err)
+			}
+		}
+	}
+
+	return nil
+}
+
+// checkFile analyzes an open file and publishes its diagnostics, reporting
+// whether the declarations it exports to its siblings changed since it was
+// last checked.
+func (h *langHandler) checkFile(ctx context.Context, uri DocumentURI, f *File, fp string) (bool, error) {
+	f.mu.Lock()
+	f.processing = true
+	f.mu.Unlock()
+
 	analysis, err := h.analyzeDirectory(ctx, uri, fp)
 	if err != nil {
 		versionNumber, diagnostics := h.finishFileUpdate(f, emptyFileAnalysis())
 		h.publishDiagnostics(ctx, uri, diagnostics, versionNumber)
-		return err
+		return false, err
 	}
 
 	versionNumber, diagnostics := h.finishFileUpdate(f, analysis)
@@ -229,7 +267,102 @@ func (h *langHandler) updateFile(ctx context.Context, uri DocumentURI, text stri
 	// Publish diagnostics to the client.
 	h.publishDiagnostics(ctx, uri, diagnostics, versionNumber)
 
-	return nil
+	var signature string
+	if analysis.AST != nil {
+		signature = declarationSignature(analysis.AST.Forms)
+	}
+	h.mu.Lock()
+	prev, seen := h.signatures[filepath.Clean(fp)]
+	h.signatures[filepath.Clean(fp)] = signature
+	h.mu.Unlock()
+	return seen && prev != signature, nil
+}
+
+type openSibling struct {
+	uri  DocumentURI
+	path string
+	file *File
+}
+
+// openSiblings returns the other open files in the same module directory as
+// fp.
+func (h *langHandler) openSiblings(uri DocumentURI, fp string) []openSibling {
+	h.mu.Lock()
+	defer h.mu.Unlock()
+	dir := filepath.Dir(filepath.Clean(fp))
+	var siblings []openSibling
+	for openURI, f := range h.files {
+		if openURI == uri {
+			continue
+		}
+		path, err := fromURI(openURI)
+		if err != nil || filepath.Dir(filepath.Clean(path)) != dir || filepath.Ext(path) != ".dang" {
+			continue
+		}
+		siblings = append(siblings, openSibling{uri: openURI, path: path, file: f})
+	}
+	sort.Slice(siblings, func(i, j int) bool { return siblings[i].path < siblings[j].path })
+	return siblings
+}
+
+// declarationSignature fingerprints the declarations a file exports to its
+// siblings: each one's name, kind, type, visibility, and the union members or
+// interfaces it names, but not where it is or how it's implemented.
+func declarationSignature(forms []dang.Node) string {
+	var sig strings.Builder
+	var write func(prefix string, symbols []DocumentSymbol)
+	write = func(prefix string, symbols []DocumentSymbol) {
+		for _, sym := range symbols {
+			fmt.Fprintf(&sig, "%s%s %d %s\n", prefix, sym.Name, sym.Kind, sym.Detail)
+			write(prefix+sym.Name+".", sym.Children)
+		}
+	}
+	write("", documentSymbols(forms))
+	// Document symbols leave out the rest of what siblings check against,
+	// e.g. a case over a union is exhaustive only for its current members.
+	for _, form := range forms {
+		writeDeclarationShape(&sig, "", form)
+	}
+	return sig.String()
+}
+
+// writeDeclarationShape writes a declaration's visibility and the types it
+// names (a union's members, or the interfaces a type implements), then does
+// the same for the members of its body.
+func writeDeclarationShape(sig *strings.Builder, prefix string, form dang.Node) {
+	var name *dang.Symbol
+	var vis dang.Visibility
+	var related []*dang.Symbol
+	var body *dang.Block
+	switch n := form.(type) {
+	case *dang.ObjectDecl:
+		name, vis, related, body = n.Name, n.Visibility, n.Implements, n.Value
+	case *dang.InterfaceDecl:
+		name, vis, related, body = n.Name, n.Visibility, n.Implements, n.Value
+	case *dang.ScalarDecl:
+		name, vis, body = n.Name, n.Visibility, n.Value
+	case *dang.UnionDecl:
+		name, vis, related = n.Name, n.Visibility, n.Members
+	case *dang.EnumDecl:
+		name, vis = n.Name, n.Visibility
+	case *dang.FieldDecl:
+		name, vis = n.Name, n.Visibility
+	}
+	if name == nil {
+		return
+	}
+	names := make([]string, 0, len(related))
+	for _, sym := range related {
+		if sym != nil {
+			names = append(names, sym.Name)
+		}
+	}
+	fmt.Fprintf(sig, "%s%s visibility=%d %s\n", prefix, name.Name, vis, strings.Join(names, ","))
+	if body != nil {
+		for _, member := range body.Forms {
+			writeDeclarationShape(sig, prefix+name.Name+".", member)
+		}
+	}
 }
 
 type directoryFile struct {
@@ -272,7 +405,7 @@ func (h *langHandler) analyzeDirectory(ctx context.Context, uri DocumentURI, fp
 			}
 			slog.WarnContext(ctx, "failed to parse Dang code for LSP", "path", path, "error", err)
 			if sameFile(path, fp) {
-				analysis.Diagnostics = append(analysis.Diagnostics, h.errorToDiagnostics(err, uri)...)
+				analysis.Diagnostics = append(analysis.Diagnostics, h.errorToDiagnostics(err, uri, nil)...)
 			}
 			continue
 		}
@@ -280,6 +413,9 @@ func (h *langHandler) analyzeDirectory(ctx context.Context, uri DocumentURI, fp
 		if sameFile(path, fp) {
 			currentBlock = block
 			analysis.AST = block
+			for _, err := range block.SyntaxErrors {
+				analysis.Diagnostics = append(analysis.Diagnostics, h.errorToDiagnostics(err, uri, nil)...)
+			}
 		}
 
 		parsedFiles = append(parsedFiles, directoryFile{
@@ -298,18 +434,7 @@ func (h *langHandler) analyzeDirectory(ctx context.Context, uri DocumentURI, fp
 	// URI for each declaration.
 	analysis.Symbols = h.buildDirectorySymbolTable(parsedFiles, uri)
 
-	// Resolve import configs once for the directory, using a cache to avoid
-	// spawning new dagger sessions on every keystroke.
-	importConfigs, ctx := h.resolveImports(ctx, fileDir)
-	// Attach a long-lived schema-module cache for this directory BEFORE
-	// installing the import configs, so the configs' helpers find an existing
-	// cache and reuse it. Without this, each analyzeDirectory call gets a
-	// fresh cache and ImportDecls in re-parsed sibling files build divergent
-	// *Type instances — types like Test.ServerInfo fail to unify.
-	ctx = dang.WithSchemaModuleCache(ctx, h.schemaModuleCacheFor(fileDir))
-	if len(importConfigs) > 0 {
-		ctx = dang.ContextWithImportConfigs(ctx, importConfigs...)
-	}
+	ctx, imports := h.directoryInferenceContext(ctx, fileDir)
 
 	// Collect inference warnings (e.g. the rescue laziness analysis) into a
 	// sink instead of letting them print to the LSP process stderr on every
@@ -323,7 +448,7 @@ func (h *langHandler) analyzeDirectory(ctx context.Context, uri DocumentURI, fp
 	typeScope := dang.NewPreludeTypeScope("")
 	fresh := hm.NewSimpleFresher()
 	if err := dang.InferDirectoryFilesFocused(ctx, blocks, currentBlock, typeScope, fresh); err != nil {
-		analysis.Diagnostics = append(analysis.Diagnostics, h.errorToDiagnosticsForPath(err, uri, fp)...)
+		analysis.Diagnostics = append(analysis.Diagnostics, h.errorToDiagnosticsForPath(err, uri, fp, imports)...)
 	}
 	analysis.Diagnostics = append(analysis.Diagnostics, warningDiagnostics(inferWarnings.Take(), fp)...)
 	// The block's TypeScope composes the shared dirScope with the file's own
@@ -342,6 +467,25 @@ func (h *langHandler) analyzeDirectory(ctx context.Context, uri DocumentURI, fp
 	return analysis, nil
 }
 
+// directoryInferenceContext prepares ctx for running inference over the files
+// in fileDir: project config, resolved imports, and the directory's schema
+// module cache. It also returns the resolved imports.
+func (h *langHandler) directoryInferenceContext(ctx context.Context, fileDir string) (context.Context, []dang.ImportConfig) {
+	// Resolve import configs once for the directory, using a cache to avoid
+	// spawning new dagger sessions on every keystroke.
+	importConfigs, ctx := h.resolveImports(ctx, fileDir)
+	// Attach a long-lived schema-module cache for this directory BEFORE
+	// installing the import configs, so the configs' helpers find an existing
+	// cache and reuse it. Without this, each analyzeDirectory call gets a
+	// fresh cache and ImportDecls in re-parsed sibling files build divergent
+	// *Type instances — types like Test.ServerInfo fail to unify.
+	ctx = dang.WithSchemaModuleCache(ctx, h.schemaModuleCacheFor(fileDir))
+	if len(importConfigs) > 0 {
+		ctx = dang.ContextWithImportConfigs(ctx, importConfigs...)
+	}
+	return ctx, importConfigs
+}
+
 func (h *langHandler) finishFileUpdate(f *File, analysis *fileAnalysis) (int, []Diagnostic) {
 	f.mu.Lock()
 	defer f.mu.Unlock()
@@ -466,14 +610,12 @@ func (h *langHandler) parseFileCached(path string) (*dang.FileBlock, error) {
 }
 
 func (h *langHandler) parseAndStore(path, text string, entry parsedFile) (*dang.FileBlock, error) {
-	parsed, err := dang.ParseWithRecovery(path, []byte(text), dang.GlobalStore("filePath", path))
+	// Recover from syntax errors so that hover, completion, and symbols keep
+	// working on the rest of a file that's mid-edit.
+	block, err := dang.ParseRecovering(path, []byte(text), dang.GlobalStore("filePath", path))
 	if err != nil {
 		return nil, err
 	}
-	block, ok := parsed.(*dang.FileBlock)
-	if !ok {
-		return nil, fmt.Errorf("parsed result for %s is not a FileBlock", path)
-	}
 	entry.block = block
 	h.mu.Lock()
 	h.parseCache[path] = entry
@@ -660,6 +802,11 @@ func (h *langHandler) collectSymbols(uri DocumentURI, nodes []dang.Node, st *Sym
 			declared := node.DeclaredSymbols()
 			for _, name := range declared {
 				loc := node.GetSourceLocation()
+				// Point at the name itself when the node has one, not at
+				// the leading keyword.
+				if sym := declarationName(node); sym != nil && sym.Name == name && sym.Loc != nil {
+					loc = sym.Loc
+				}
 				if loc != nil {
 					// LSP uses 0-based line/column, SourceLocation uses 1-based
 					st.Definitions[name] = &SymbolInfo{
@@ -752,12 +899,12 @@ func (h *langHandler) publishDiagnostics(ctx context.Context, uri DocumentURI, d
 	}
 }
 
-func (h *langHandler) errorToDiagnosticsForPath(err error, uri DocumentURI, path string) []Diagnostic {
+func (h *langHandler) errorToDiagnosticsForPath(err error, uri DocumentURI, path string, imports []dang.ImportConfig) []Diagnostic {
 	var inferErrs *dang.InferenceErrors
 	if errors.As(err, &inferErrs) {
 		var ds []Diagnostic
 		for _, e := range inferErrs.Errors {
-			ds = append(ds, h.errorToDiagnosticsForPath(e, uri, path)...)
+			ds = append(ds, h.errorToDiagnosticsForPath(e, uri, path, imports)...)
 		}
 		return ds
 	}
@@ -766,7 +913,7 @@ func (h *langHandler) errorToDiagnosticsForPath(err error, uri DocumentURI, path
 		return nil
 	}
 
-	return h.errorToDiagnostics(err, uri)
+	return h.errorToDiagnostics(err, uri, imports)
 }
 
 func errorLocation(err error) *dang.SourceLocation {
@@ -785,8 +932,10 @@ func errorLocation(err error) *dang.SourceLocation {
 	return nil
 }
 
-// errorToDiagnostic converts a Dang error to an LSP Diagnostic
-func (h *langHandler) errorToDiagnostics(err error, uri DocumentURI) []Diagnostic {
+// errorToDiagnostic converts a Dang error to an LSP Diagnostic. A reference to
+// the name of one of imports that the file doesn't import carries a
+// missingImport payload for the quick fix.
+func (h *langHandler) errorToDiagnostics(err error, uri DocumentURI, imports []dang.ImportConfig) []Diagnostic {
 	slog.Warn("converting error", "type", fmt.Sprintf("%T", err), "err", err)
 	for e := errors.Unwrap(err); e != nil && e != err; e = errors.Unwrap(e) {
 		slog.Warn("unwrapped", "type", fmt.Sprintf("%T", e), "err", e)
@@ -796,7 +945,7 @@ func (h *langHandler) errorToDiagnostics(err error, uri DocumentURI) []Diagnosti
 	if errors.As(err, &inferErrs) {
 		var ds []Diagnostic
 		for _, e := range inferErrs.Errors {
-			ds = append(ds, h.errorToDiagnostics(e, uri)...)
+			ds = append(ds, h.errorToDiagnostics(e, uri, imports)...)
 		}
 		return ds
 	}
@@ -847,17 +996,25 @@ func (h *langHandler) errorToDiagnostics(err error, uri DocumentURI) []Diagnosti
 		message = sourceErr.Inner.Error()
 	}
 
-	return []Diagnostic{
-		{
-			Range: Range{
-				Start: Position{Line: startLine, Character: startCol},
-				End:   Position{Line: endLine, Character: endCol},
-			},
-			Severity: 1, // Error
-			Source:   stringPtr("dang"),
-			Message:  message,
+	diag := Diagnostic{
+		Range: Range{
+			Start: Position{Line: startLine, Character: startCol},
+			End:   Position{Line: endLine, Character: endCol},
 		},
+		Severity: 1, // Error
+		Source:   stringPtr("dang"),
+		Message:  message,
+	}
+	var undefined dang.UndefinedNameError
+	if errors.As(err, &undefined) {
+		for _, imp := range imports {
+			if imp.Name == undefined.Name {
+				diag.Data = missingImport{Import: imp.Name}
+				break
+			}
+		}
 	}
+	return []Diagnostic{diag}
 }
 
 func stringPtr(s string) *string {
@@ -906,10 +1063,26 @@ func (h *langHandler) Assign(ctx context.Context, method string) jrpc2.Handler {
 		return h.handleTextDocumentHover
 	case "textDocument/codeAction":
 		return h.handleTextDocumentCodeAction
+	case "textDocument/references":
+		return h.handleTextDocumentReferences
 	case "textDocument/rename":
 		return h.handleTextDocumentRename
 	case "textDocument/formatting":
 		return h.handleTextDocumentFormatting
+	case "textDocument/rangeFormatting":
+		return h.handleTextDocumentRangeFormatting
+	case "textDocument/semanticTokens/full":
+		return h.handleTextDocumentSemanticTokensFull
+	case "textDocument/semanticTokens/range":
+		return h.handleTextDocumentSemanticTokensRange
+	case "textDocument/inlayHint":
+		return h.handleTextDocumentInlayHint
+	case "inlayHint/resolve":
+		return h.handleInlayHintResolve
+	case "textDocument/documentSymbol":
+		return h.handleTextDocumentDocumentSymbol
+	case "textDocument/signatureHelp":
+		return h.handleTextDocumentSignatureHelp
 	case "workspace/symbol":
 		return h.handleWorkspaceSymbol
 	case "workspace/didChangeConfiguration":
@@ -5,6 +5,8 @@ import (
 	"os"
 	"path/filepath"
 	"testing"
+
+	"github.com/vito/dang/v2/pkg/dang"
 )
 
 func TestFailedUpdateClearsAnalysis(t *testing.T) {
@@ -74,3 +76,114 @@ func TestFailedUpdateClearsAnalysis(t *testing.T) {
 		t.Fatalf("failed update kept stale symbols: %#v", snapshot.Symbols)
 	}
 }
+
+func TestSignatureChangeRechecksOpenSiblings(t *testing.T) {
+	ctx := context.Background()
+	h, uris := openModule(t, map[string]string{
+		"greet.dang": "pub greet(name: String!): String! { \"hi \" + name }\n",
+		"main.dang":  "pub msg: String! { greet(\"bob\") }\n",
+	})
+
+	if got := h.waitForFile(uris["main.dang"]).Diagnostics; len(got) != 0 {
+		t.Fatalf("unexpected diagnostics: %#v", got)
+	}
+	// Mark the live file (not a snapshot) so a re-check is observable.
+	h.mu.Lock()
+	mainFile := h.files[uris["main.dang"]]
+	h.mu.Unlock()
+	mainFile.mu.Lock()
+	mainFile.Diagnostics = []Diagnostic{{Message: "not re-checked"}}
+	mainFile.mu.Unlock()
+
+	// A body-only edit leaves dependents alone.
+	version := 2
+	if err := h.updateFile(ctx, uris["greet.dang"], "pub greet(name: String!): String! { \"hello \" + name }\n", &version); err != nil {
+		t.Fatalf("update: %v", err)
+	}
+	if got := h.waitForFile(uris["main.dang"]).Diagnostics; len(got) != 1 || got[0].Message != "not re-checked" {
+		t.Fatalf("body-only edit re-checked sibling: %#v", got)
+	}
+
+	// A signature change re-checks them.
+	version = 3
+	if err := h.updateFile(ctx, uris["greet.dang"], "pub greet(name: Int!): String! { \"hello\" }\n", &version); err != nil {
+		t.Fatalf("update: %v", err)
+	}
+	got := h.waitForFile(uris["main.dang"]).Diagnostics
+	if len(got) == 0 || got[0].Message == "not re-checked" {
+		t.Fatalf("signature change did not re-check sibling: %#v", got)
+	}
+}
+
+func TestDeclarationSignatureCoversTypeShape(t *testing.T) {
+	signature := func(src string) string {
+		t.Helper()
+		parsed, err := dang.Parse("shape.dang", []byte(src))
+		if err != nil {
+			t.Fatalf("parse %q: %v", src, err)
+		}
+		return declarationSignature(parsed.(*dang.FileBlock).Forms)
+	}
+
+	base := signature("union Pet = Cat | Dog\n")
+	if signature("union Pet = Cat | Dog | Bird\n") == base {
+		t.Error("adding a union member left the signature unchanged")
+	}
+
+	plain := signature("type Dog { name: String! = \"rex\" }\n")
+	if signature("type Dog implements Named { name: String! = \"rex\" }\n") == plain {
+		t.Error("implementing an interface left the signature unchanged")
+	}
+	if signature("type Dog { let name: String! = \"rex\" }\n") == plain {
+		t.Error("hiding a member left the signature unchanged")
+	}
+}
+
+func TestUnionEditRechecksOpenSiblings(t *testing.T) {
+	ctx := context.Background()
+	h, uris := openModule(t, map[string]string{
+		"pets.dang": "type Cat { name: String! = \"tom\" }\ntype Dog { name: String! = \"rex\" }\nunion Pet = Cat | Dog\n",
+		"main.dang": "pub describe(pet: Pet!): String! {\n  case (pet) {\n    c: Cat => c.name\n    d: Dog => d.name\n  }\n}\n",
+	})
+
+	h.waitForFile(uris["main.dang"])
+	h.mu.Lock()
+	mainFile := h.files[uris["main.dang"]]
+	h.mu.Unlock()
+	mainFile.mu.Lock()
+	mainFile.Diagnostics = []Diagnostic{{Message: "not re-checked"}}
+	mainFile.mu.Unlock()
+
+	version := 2
+	if err := h.updateFile(ctx, uris["pets.dang"], "type Cat { name: String! = \"tom\" }\ntype Dog { name: String! = \"rex\" }\ntype Bird { name: String! = \"tweety\" }\nunion Pet = Cat | Dog | Bird\n", &version); err != nil {
+		t.Fatalf("update: %v", err)
+	}
+	if got := h.waitForFile(uris["main.dang"]).Diagnostics; len(got) == 1 && got[0].Message == "not re-checked" {
+		t.Fatalf("adding a union member did not re-check sibling: %#v", got)
+	}
+}
+
+func TestSyntaxErrorKeepsRestOfFile(t *testing.T) {
+	text := "pub before = 1\n\npub broken = = 2\n\npub after = before\n\npub again = broken\n"
+	h, uris := openModule(t, map[string]string{"main.dang": text})
+
+	snapshot := h.waitForFile(uris["main.dang"])
+	if snapshot == nil || snapshot.AST == nil {
+		t.Fatalf("syntax error discarded the AST")
+	}
+	if len(snapshot.Diagnostics) != 1 {
+		t.Fatalf("expected only the syntax error, got %#v", snapshot.Diagnostics)
+	}
+	if line := snapshot.Diagnostics[0].Range.Start.Line; line != 2 {
+		t.Fatalf("syntax error reported on line %d, want 2", line)
+	}
+	// A use of the broken declaration doesn't cascade into "not found".
+	for _, name := range []string{"before", "after", "again"} {
+		if _, ok := snapshot.Symbols.Definitions[name]; !ok {
+			t.Errorf("missing symbol %q after recovery", name)
+		}
+		if _, ok := snapshot.TypeScope.SchemeOf(name); !ok {
+			t.Errorf("missing type for %q after recovery", name)
+		}
+	}
+}
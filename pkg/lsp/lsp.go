@@ -70,16 +70,21 @@ type ServerCapabilitiesWorkspace struct {
 
 // ServerCapabilities is
 type ServerCapabilities struct {
-	TextDocumentSync           TextDocumentSyncKind         `json:"textDocumentSync,omitempty"`
-	DocumentSymbolProvider     bool                         `json:"documentSymbolProvider,omitempty"`
-	CompletionProvider         *CompletionProvider          `json:"completionProvider,omitempty"`
-	DefinitionProvider         bool                         `json:"definitionProvider,omitempty"`
-	DocumentFormattingProvider bool                         `json:"documentFormattingProvider,omitempty"`
-	HoverProvider              bool                         `json:"hoverProvider,omitempty"`
-	CodeActionProvider         bool                         `json:"codeActionProvider,omitempty"`
-	RenameProvider             bool                         `json:"renameProvider,omitempty"`
-	WorkspaceSymbolProvider    bool                         `json:"workspaceSymbolProvider,omitempty"`
-	Workspace                  *ServerCapabilitiesWorkspace `json:"workspace,omitempty"`
+	TextDocumentSync                TextDocumentSyncKind         `json:"textDocumentSync,omitempty"`
+	DocumentSymbolProvider          bool                         `json:"documentSymbolProvider,omitempty"`
+	CompletionProvider              *CompletionProvider          `json:"completionProvider,omitempty"`
+	DefinitionProvider              bool                         `json:"definitionProvider,omitempty"`
+	DocumentFormattingProvider      bool                         `json:"documentFormattingProvider,omitempty"`
+	DocumentRangeFormattingProvider bool                         `json:"documentRangeFormattingProvider,omitempty"`
+	HoverProvider                   bool                         `json:"hoverProvider,omitempty"`
+	ReferencesProvider              bool                         `json:"referencesProvider,omitempty"`
+	CodeActionProvider              bool                         `json:"codeActionProvider,omitempty"`
+	RenameProvider                  bool                         `json:"renameProvider,omitempty"`
+	SemanticTokensProvider          *SemanticTokensOptions       `json:"semanticTokensProvider,omitempty"`
+	InlayHintProvider               *InlayHintOptions            `json:"inlayHintProvider,omitempty"`
+	SignatureHelpProvider           *SignatureHelpOptions        `json:"signatureHelpProvider,omitempty"`
+	WorkspaceSymbolProvider         bool                         `json:"workspaceSymbolProvider,omitempty"`
+	Workspace                       *ServerCapabilitiesWorkspace `json:"workspace,omitempty"`
 }
 
 // TextDocumentItem is
@@ -207,6 +212,7 @@ type Diagnostic struct {
 	Message            string                         `json:"message"`
 	Tags               []DiagnosticTag                `json:"tags,omitempty"`
 	RelatedInformation []DiagnosticRelatedInformation `json:"relatedInformation,omitempty"`
+	Data               any                            `json:"data,omitempty"`
 }
 
 // PublishDiagnosticsParams is
@@ -225,6 +231,13 @@ type DocumentFormattingParams struct {
 	Options      FormattingOptions      `json:"options"`
 }
 
+// DocumentRangeFormattingParams is
+type DocumentRangeFormattingParams struct {
+	TextDocument TextDocumentIdentifier `json:"textDocument"`
+	Range        Range                  `json:"range"`
+	Options      FormattingOptions      `json:"options"`
+}
+
 // TextEdit is
 type TextEdit struct {
 	Range   Range  `json:"range"`
@@ -236,13 +249,56 @@ type DocumentSymbolParams struct {
 	TextDocument TextDocumentIdentifier `json:"textDocument"`
 }
 
+// SymbolKind is
+type SymbolKind int
+
+// FileSymbol is
+const (
+	FileSymbol          SymbolKind = 1
+	ModuleSymbol        SymbolKind = 2
+	NamespaceSymbol     SymbolKind = 3
+	PackageSymbol       SymbolKind = 4
+	ClassSymbol         SymbolKind = 5
+	MethodSymbol        SymbolKind = 6
+	PropertySymbol      SymbolKind = 7
+	FieldSymbol         SymbolKind = 8
+	ConstructorSymbol   SymbolKind = 9
+	EnumSymbol          SymbolKind = 10
+	InterfaceSymbol     SymbolKind = 11
+	FunctionSymbol      SymbolKind = 12
+	VariableSymbol      SymbolKind = 13
+	ConstantSymbol      SymbolKind = 14
+	StringSymbol        SymbolKind = 15
+	NumberSymbol        SymbolKind = 16
+	BooleanSymbol       SymbolKind = 17
+	ArraySymbol         SymbolKind = 18
+	ObjectSymbol        SymbolKind = 19
+	KeySymbol           SymbolKind = 20
+	NullSymbol          SymbolKind = 21
+	EnumMemberSymbol    SymbolKind = 22
+	StructSymbol        SymbolKind = 23
+	EventSymbol         SymbolKind = 24
+	OperatorSymbol      SymbolKind = 25
+	TypeParameterSymbol SymbolKind = 26
+)
+
+// DocumentSymbol is
+type DocumentSymbol struct {
+	Name           string           `json:"name"`
+	Detail         string           `json:"detail,omitempty"`
+	Kind           SymbolKind       `json:"kind"`
+	Range          Range            `json:"range"`
+	SelectionRange Range            `json:"selectionRange"`
+	Children       []DocumentSymbol `json:"children,omitempty"`
+}
+
 // SymbolInformation is
 type SymbolInformation struct {
-	Name          string   `json:"name"`
-	Kind          int64    `json:"kind"`
-	Deprecated    bool     `json:"deprecated"`
-	Location      Location `json:"location"`
-	ContainerName *string  `json:"containerName"`
+	Name          string     `json:"name"`
+	Kind          SymbolKind `json:"kind"`
+	Deprecated    bool       `json:"deprecated"`
+	Location      Location   `json:"location"`
+	ContainerName *string    `json:"containerName"`
 }
 
 // WorkspaceSymbolParams is
@@ -461,6 +517,116 @@ type WorkspaceFolder struct {
 	Name string      `json:"name"`
 }
 
+// ReferenceParams is
+type ReferenceParams struct {
+	TextDocumentPositionParams
+	WorkDoneProgressParams
+	PartialResultParams
+	Context ReferenceContext `json:"context"`
+}
+
+// ReferenceContext is
+type ReferenceContext struct {
+	IncludeDeclaration bool `json:"includeDeclaration"`
+}
+
+// SemanticTokensLegend is
+type SemanticTokensLegend struct {
+	TokenTypes     []string `json:"tokenTypes"`
+	TokenModifiers []string `json:"tokenModifiers"`
+}
+
+// SemanticTokensOptions is
+type SemanticTokensOptions struct {
+	Legend SemanticTokensLegend `json:"legend"`
+	Range  bool                 `json:"range,omitempty"`
+	Full   bool                 `json:"full,omitempty"`
+}
+
+// SemanticTokensParams is
+type SemanticTokensParams struct {
+	WorkDoneProgressParams
+	PartialResultParams
+	TextDocument TextDocumentIdentifier `json:"textDocument"`
+}
+
+// SemanticTokensRangeParams is
+type SemanticTokensRangeParams struct {
+	WorkDoneProgressParams
+	PartialResultParams
+	TextDocument TextDocumentIdentifier `json:"textDocument"`
+	Range        Range                  `json:"range"`
+}
+
+// SemanticTokens is
+type SemanticTokens struct {
+	Data []uint32 `json:"data"`
+}
+
+// InlayHintOptions is
+type InlayHintOptions struct {
+	ResolveProvider bool `json:"resolveProvider,omitempty"`
+}
+
+// InlayHintParams is
+type InlayHintParams struct {
+	WorkDoneProgressParams
+	TextDocument TextDocumentIdentifier `json:"textDocument"`
+	Range        Range                  `json:"range"`
+}
+
+// InlayHintKind is
+type InlayHintKind int
+
+const (
+	// TypeInlayHint is
+	TypeInlayHint InlayHintKind = 1
+	// ParameterInlayHint is
+	ParameterInlayHint InlayHintKind = 2
+)
+
+// InlayHint is
+type InlayHint struct {
+	Position    Position       `json:"position"`
+	Label       string         `json:"label"`
+	Kind        InlayHintKind  `json:"kind,omitempty"`
+	Tooltip     *MarkupContent `json:"tooltip,omitempty"`
+	PaddingLeft bool           `json:"paddingLeft,omitempty"`
+	Data        any            `json:"data,omitempty"`
+}
+
+// SignatureHelpOptions is
+type SignatureHelpOptions struct {
+	TriggerCharacters []string `json:"triggerCharacters,omitempty"`
+}
+
+// SignatureHelpParams is
+type SignatureHelpParams struct {
+	TextDocumentPositionParams
+}
+
+// SignatureHelp is
+type SignatureHelp struct {
+	Signatures      []SignatureInformation `json:"signatures"`
+	ActiveSignature int                    `json:"activeSignature"`
+	ActiveParameter int                    `json:"activeParameter"`
+}
+
+// SignatureInformation is
+type SignatureInformation struct {
+	Label         string                 `json:"label"`
+	Documentation *MarkupContent         `json:"documentation,omitempty"`
+	Parameters    []ParameterInformation `json:"parameters"`
+}
+
+// ParameterInformation is
+type ParameterInformation struct {
+	// Label is the [start, end) offsets of the parameter within the
+	// signature label.
+	Label         [2]int         `json:"label"`
+	Documentation *MarkupContent `json:"documentation,omitempty"`
+}
+
 // RenameParams is
 type RenameParams struct {
 	TextDocument TextDocumentIdentifier `json:"textDocument"`
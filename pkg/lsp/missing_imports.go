@@ -0,0 +1,89 @@
+package lsp
+
+import (
+	"encoding/json"
+	"fmt"
+
+	"github.com/vito/dang/v2/pkg/dang"
+)
+
+// missingImport is the data payload of a diagnostic for a reference to an
+// import the file doesn't declare. Clients hand it back with the diagnostic
+// when requesting code actions.
+type missingImport struct {
+	Import string `json:"import"`
+}
+
+// missingImportOf decodes the payload of a diagnostic, whether it is one we
+// published or one a client echoed back as JSON.
+func missingImportOf(diag Diagnostic) (missingImport, bool) {
+	switch data := diag.Data.(type) {
+	case missingImport:
+		return data, true
+	case nil:
+		return missingImport{}, false
+	default:
+		raw, err := json.Marshal(data)
+		if err != nil {
+			return missingImport{}, false
+		}
+		var imp missingImport
+		if err := json.Unmarshal(raw, &imp); err != nil || imp.Import == "" {
+			return missingImport{}, false
+		}
+		return imp, true
+	}
+}
+
+// importCodeAction offers to add `import <name>` to a file, fixing diag.
+func importCodeAction(uri DocumentURI, forms []dang.Node, name string, diag Diagnostic) CodeAction {
+	return CodeAction{
+		Title:       fmt.Sprintf("Import %s", name),
+		Kind:        QuickFix,
+		Diagnostics: []Diagnostic{diag},
+		IsPreferred: true,
+		Edit: &WorkspaceEdit{
+			Changes: map[string][]TextEdit{
+				string(uri): {importEdit(forms, name)},
+			},
+		},
+	}
+}
+
+// importEdit inserts `import <name>` among the file's imports, keeping them
+// sorted by name as the formatter does. A file with no imports gets it on the
+// first line, separated from the rest of the file by a blank line.
+func importEdit(forms []dang.Node, name string) TextEdit {
+	text := "import " + name + "\n"
+	after := -1
+	others := false
+	for _, form := range forms {
+		imp, ok := form.(*dang.ImportDecl)
+		if !ok {
+			others = true
+			continue
+		}
+		// Auto-imports are injected without a location.
+		if imp.Name == nil || imp.Loc == nil {
+			continue
+		}
+		if imp.Name.Name > name {
+			return insertLine(imp.Loc.Line-1, text)
+		}
+		// Dang lines are 1-based, so this is the line after the import.
+		after = imp.Loc.Line
+	}
+	if after >= 0 {
+		return insertLine(after, text)
+	}
+	if others {
+		text += "\n"
+	}
+	return insertLine(0, text)
+}
+
+// insertLine inserts text at the start of line.
+func insertLine(line int, text string) TextEdit {
+	pos := Position{Line: line, Character: 0}
+	return TextEdit{Range: Range{Start: pos, End: pos}, NewText: text}
+}
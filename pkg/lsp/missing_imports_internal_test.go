@@ -0,0 +1,103 @@
+package lsp
+
+import (
+	"context"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/vito/dang/v2/pkg/dang"
+)
+
+func TestMissingImportCodeAction(t *testing.T) {
+	ctx := context.Background()
+	schema, err := filepath.Abs("../../tests/gqlserver/schema.graphqls")
+	if err != nil {
+		t.Fatal(err)
+	}
+	dir := t.TempDir()
+	config := "[imports.Test]\nschema = " + `"` + filepath.ToSlash(schema) + `"` + "\n"
+	if err := os.WriteFile(filepath.Join(dir, "dang.toml"), []byte(config), 0o644); err != nil {
+		t.Fatal(err)
+	}
+	path := filepath.Join(dir, "main.dang")
+	text := "pub info = Test.serverInfo\n"
+	if err := os.WriteFile(path, []byte(text), 0o644); err != nil {
+		t.Fatal(err)
+	}
+
+	h := NewHandler(ctx)
+	uri := toURI(path)
+	if err := h.openFile(uri, "dang", 1); err != nil {
+		t.Fatal(err)
+	}
+	version := 1
+	if err := h.updateFile(ctx, uri, text, &version); err != nil {
+		t.Fatal(err)
+	}
+
+	f := h.waitForFile(uri)
+	var diag *Diagnostic
+	for i, d := range f.Diagnostics {
+		if _, ok := missingImportOf(d); ok {
+			diag = &f.Diagnostics[i]
+		}
+	}
+	if diag == nil {
+		t.Fatalf("no missing import diagnostic in %+v", f.Diagnostics)
+	}
+
+	// The client sends the payload back as plain JSON.
+	raw, err := json.Marshal(diag)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var echoed Diagnostic
+	if err := json.Unmarshal(raw, &echoed); err != nil {
+		t.Fatal(err)
+	}
+	if imp, ok := missingImportOf(echoed); !ok || imp.Import != "Test" {
+		t.Fatalf("echoed payload = %+v, want import Test", echoed.Data)
+	}
+}
+
+func TestImportEditKeepsImportsSorted(t *testing.T) {
+	for _, tc := range []struct {
+		name string
+		src  string
+		want TextEdit
+	}{
+		{
+			name: "before a later import",
+			src:  "import Zed\n\npub x = 1\n",
+			want: insertLine(0, "import Test\n"),
+		},
+		{
+			name: "after an earlier import",
+			src:  "import Alpha\nimport Zed\n\npub x = 1\n",
+			want: insertLine(1, "import Test\n"),
+		},
+		{
+			name: "after the last import",
+			src:  "import Alpha\n\npub x = 1\n",
+			want: insertLine(1, "import Test\n"),
+		},
+		{
+			name: "no imports",
+			src:  "pub x = 1\n",
+			want: insertLine(0, "import Test\n\n"),
+		},
+	} {
+		t.Run(tc.name, func(t *testing.T) {
+			parsed, err := dang.Parse("test.dang", []byte(tc.src))
+			if err != nil {
+				t.Fatal(err)
+			}
+			got := importEdit(parsed.(*dang.FileBlock).Forms, "Test")
+			if got != tc.want {
+				t.Errorf("importEdit = %+v, want %+v", got, tc.want)
+			}
+		})
+	}
+}